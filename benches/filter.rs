@@ -0,0 +1,97 @@
+//! Measures the filtering done by the application on every update, on the embedded word list
+//!
+//! Run with `cargo bench --no-default-features --bench filter`, which skips building the GUI.
+//! Before the measurements, the number of allocations made by a single filtering pass is printed
+//! for each constraint set.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use wordle_finder::{Constraints, constraints, words};
+
+/// Counts the allocations made through the global allocator
+struct CountingAllocator;
+
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Representative constraint sets, from the start of a puzzle to its end
+fn constraint_sets() -> Vec<(&'static str, Constraints)> {
+    vec![
+        ("empty", Constraints::new(Vec::new(), Vec::new(), "", "")),
+        (
+            "one green",
+            Constraints::new(vec![(0, 's')], Vec::new(), "", ""),
+        ),
+        (
+            "five greens",
+            Constraints::new(
+                vec![(0, 'c'), (1, 'r'), (2, 'a'), (3, 'n'), (4, 'e')],
+                Vec::new(),
+                "",
+                "",
+            ),
+        ),
+        (
+            "heavy exclude",
+            Constraints::new(Vec::new(), vec![(1, 'a')], "a", "bcdfghjklmpqrstvwxyz"),
+        ),
+    ]
+}
+
+fn filter(criterion: &mut Criterion) {
+    let words = words::parse_word_file(words::EMBEDDED_ALL_WORDS, words::DEFAULT_WORD_LENGTH)
+        .expect("the embedded word list is valid");
+    let mut filtered_words = Vec::with_capacity(words.len());
+
+    for (name, constraints) in constraint_sets() {
+        // A full rebuild into a reused buffer, as when the constraints got looser
+        let allocation_count = ALLOCATION_COUNT.load(Ordering::Relaxed);
+        filtered_words.clear();
+        constraints::extend_matching(&mut filtered_words, &words, &constraints);
+        println!(
+            "{name}: {} matches, {} allocations",
+            filtered_words.len(),
+            ALLOCATION_COUNT.load(Ordering::Relaxed) - allocation_count
+        );
+
+        criterion.bench_function(&format!("rebuild/{name}"), |bencher| {
+            bencher.iter(|| {
+                filtered_words.clear();
+                constraints::extend_matching(&mut filtered_words, &words, black_box(&constraints));
+            })
+        });
+
+        // Narrowing down the whole list in place, as when the constraints only got stricter
+        criterion.bench_function(&format!("narrow/{name}"), |bencher| {
+            bencher.iter_batched(
+                || words.clone(),
+                |mut narrowed_words| {
+                    constraints::retain_matching(&mut narrowed_words, black_box(&constraints));
+                    narrowed_words
+                },
+                criterion::BatchSize::LargeInput,
+            )
+        });
+    }
+}
+
+criterion_group!(benches, filter);
+criterion_main!(benches);
@@ -1,112 +1,556 @@
 use std::{
     collections::{HashMap, HashSet},
-    fs::File,
-    io::Read,
+    time::{Duration, Instant},
 };
 
 use iced::{
     Alignment::Center,
     Background, Color, Element,
     Length::{self, Fill},
-    Padding, Theme,
+    Padding, Point, Size, Subscription, Task, Theme,
     border::rounded,
+    clipboard,
+    keyboard::{self, Key, key::Named},
+    mouse::Interaction,
+    time,
     widget::{
-        column, container, row, scrollable, text, text_editor,
+        Id, Row, Text, column, container, mouse_area, operation,
+        operation::AbsoluteOffset,
+        pick_list, rich_text, row, scrollable, slider, span, text, text_editor,
         text_editor::{Action, Content},
+        text_input, tooltip,
     },
+    window,
 };
-use iced_core::text::LineHeight;
+use iced_core::text::{LineHeight, Wrapping};
 use iced_widget::{button, container::Style, space, toggler};
+use regex::Regex;
+
+use wordle_finder::{
+    constraints::{self, Constraints, IncludeMode, LetterStatus},
+    share::ShareCode,
+    solver::{self, Feedback},
+    words::{self, NamedWordList},
+};
+
+use crate::persistence::{self, FilterSnapshot, PersistedState};
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum Message {
     PositionEditAction(usize, Action),
+    PositionExcludeAction(usize, Action),
     IncludingEditAction(Action),
     ExcludingEditAction(Action),
-    ToggleCommonWords,
-    Reset,
+    SearchEditAction(Action),
+    RegexEditAction(Action),
+    ToggleCommonWords(bool),
+    RankThresholdChanged(u32),
+    ToggleEntropySuggestion(bool),
+    ToggleHardMode(bool),
+    IncludeModeSelected(IncludeMode),
+    ToggleStats(bool),
+    SortByScore(bool),
+    SortByVowels(bool),
+    ToggleHideGuessed(bool),
+    ToggleHighContrast(bool),
+    ToggleOnlyPlausible(bool),
+    ToggleHideMaybeWords(bool),
+    ToggleHidePastAnswers(bool),
+    ToggleTextResults(bool),
+    ToggleShowRejected(bool),
+    CopyWord(String),
+    CycleTheme,
+    GuessLineEdited(String),
+    ApplyGuessLine(String),
+    ToggleTileMode(bool),
+    TileGuessEdited(String),
+    BuildTiles,
+    CycleTile(usize),
+    CommitTiles,
+    ExportResults,
+    CopyAllResults,
+    CopyEmojiGrid,
+    CopyStateString,
+    ShareCodeEdited(String),
+    ImportShareCode,
+    WordListPathEdited(String),
+    ReloadWords(String),
+    NextPage,
+    WindowResized(Size),
+    WindowMoved(Point),
+    WindowCloseRequested(window::Id),
+    IncreaseColumns,
+    DecreaseColumns,
+    PrevPage,
+    RestoreSnapshot(usize),
+    RecomputeFilters(Instant),
+    HideRemovedFlash(Instant),
+    ResultsScrolled(scrollable::Viewport),
+    ClearFilters,
+    NewPuzzle,
+    ToggleSettings,
+    TogglePin(String),
+    ShowWordInfo(String),
+    HideWordInfo,
+    AddPuzzle,
+    RemovePuzzle,
+    /// A message of the board at this index, handled as if that board was the only one
+    Puzzle(usize, Box<Message>),
+}
+
+/// Above this many guess and candidate pairs, the quadratic entropy suggestion takes too long to run
+/// on every edit, so the letter frequency suggestion is used instead
+const ENTROPY_PAIR_LIMIT: usize = 1_000_000;
+
+/// Size of the window unless given with `--width` and `--height`
+pub const INITIAL_WINDOW_SIZE: Size = Size {
+    width: 1080.0,
+    height: 600.0,
+};
+
+const DEFAULT_COLUMNS: usize = 10;
+
+/// Width of the left column holding the inputs
+const CONTROLS_WIDTH: f32 = 250.0;
+/// Height the including and excluding editors grow to as their letters wrap, about three lines,
+/// before scrolling
+const LETTERS_EDITOR_MAX_HEIGHT: f32 = 80.0;
+/// Approximate width of a character of the default monospace font
+const CHARACTER_WIDTH: f32 = 9.6;
+/// Horizontal space of a badge besides its text, its padding and the spacing after it
+const BADGE_EXTRA_WIDTH: f32 = 30.0;
+/// Width of the score shown beside the word in a badge, and the spacing before it
+const SCORE_WIDTH: f32 = 35.0;
+
+/// Time without edits after which the words are filtered, so that fast typing doesn't filter on
+/// every keystroke
+const FILTER_DEBOUNCE: Duration = Duration::from_millis(80);
+
+/// How long the number of words removed by a filtering stays beside the match count
+const REMOVED_FLASH_DURATION: Duration = Duration::from_millis(1500);
+
+/// Number of words shown at once, building badges for thousands of words makes the UI stutter
+const PAGE_SIZE: usize = 500;
+
+const EXPORT_FILE_PATH: &str = "wordle_results.txt";
+
+/// Number of snapshots kept in the history, the oldest ones being dropped first
+const HISTORY_LIMIT: usize = 50;
+
+/// Number of guesses Wordle allows
+const MAX_GUESSES: usize = 6;
+
+/// Number of puzzles solved side by side at most, like the four boards of Quordle
+const MAX_PUZZLES: usize = 4;
+
+/// Where the focus goes after an action on a position box
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PositionFocus {
+    Stay,
+    Next,
+    Previous,
+}
+
+/// How the matching words are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ResultsDisplay {
+    /// A grid of badges, with their scores
+    #[default]
+    Badges,
+    /// A single block of comma-separated words, easier to scan and copy
+    Text,
+}
+
+/// How the suggested guess was picked, shown beside it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SuggestionMethod {
+    Entropy,
+    LetterFrequency,
+    /// The entropy suggestion is on, but there are too many words for it
+    EntropyFallback,
+}
+
+impl SuggestionMethod {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Entropy => "entropy",
+            Self::LetterFrequency => "letter frequency",
+            Self::EntropyFallback => "letter frequency, too many words for entropy",
+        }
+    }
+}
+
+/// The filter editors of a board, to remember which one was used last
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Editor {
+    Position(usize),
+    PositionExclude(usize),
+    Including,
+    Excluding,
+    Search,
+    Regex,
+}
+
+impl Editor {
+    /// The editor an action comes from, scrolling over an editor doesn't select it
+    fn of(message: &Message) -> Option<Self> {
+        let (editor, action) = match message {
+            Message::PositionEditAction(index, action) => (Self::Position(*index), action),
+            Message::PositionExcludeAction(index, action) => {
+                (Self::PositionExclude(*index), action)
+            }
+            Message::IncludingEditAction(action) => (Self::Including, action),
+            Message::ExcludingEditAction(action) => (Self::Excluding, action),
+            Message::SearchEditAction(action) => (Self::Search, action),
+            Message::RegexEditAction(action) => (Self::Regex, action),
+            _ => return None,
+        };
+        (!matches!(action, Action::Scroll { .. })).then_some(editor)
+    }
+}
+
+/// Which list a word comes from, deciding the style of its badge and its place in the results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum WordTier {
+    Common,
+    Rare,
+    /// Valid, but known to never be an answer
+    Maybe,
+}
+
+/// The inputs and results of one puzzle, several of them being solved side by side in
+/// Dordle-style games
+struct PuzzleState {
+    filtered_words: Vec<String>,
+    previous_filtered_words: Vec<String>,
+    /// Words of the list missing from `filtered_words`, only computed while they are shown
+    rejected_words: Vec<String>,
+    applied_constraints: Option<Constraints>,
+    applied_only_show_common: bool,
+    applied_only_plausible: bool,
+    applied_hide_maybe_words: bool,
+    suggested_guess: Option<(String, SuggestionMethod)>,
+    /// Contradiction between the clues when no word matches
+    conflict: Option<String>,
+    /// Positional letter frequency score of each filtered word, in the same order
+    scores: Vec<usize>,
+    /// Indices of the filtered words from the highest score to the lowest
+    score_order: Vec<usize>,
+    /// Indices of the filtered words from the most distinct vowels to the fewest, ties going to
+    /// the highest score
+    vowel_order: Vec<usize>,
+    position_content: Vec<Content>,
+    position_exclude_content: Vec<Content>,
+    including_content: Content,
+    excluding_content: Content,
+    search_content: Content,
+    /// Pattern the whole word must match, on top of the other filters
+    regex_content: Content,
+    /// Pattern of the regex box when the words were last filtered
+    applied_regex: String,
+    /// Whether the pattern of the regex box doesn't compile, in which case it's ignored
+    invalid_regex: bool,
+    include_mode: IncludeMode,
+    /// Lowercase words applied from the guess line in the current puzzle
+    guessed: HashSet<String>,
+    /// Number of guesses applied in the current puzzle, repeated guesses included
+    guess_count: usize,
+    /// Guesses applied in the current puzzle and their feedback, in the order they were played
+    played_guesses: Vec<Vec<(char, Feedback)>>,
+    /// Lowercase letters of the guesses applied in the current puzzle, whatever their color
+    tried_letters: HashSet<char>,
+    /// Words set aside in the current puzzle, in the order they were pinned
+    pinned: Vec<String>,
+    /// Word whose details are shown beside the results
+    word_info: Option<String>,
+    /// Editor of the last edit, outlined while the board is the active one
+    selected_editor: Option<Editor>,
+    guess_line: String,
+    guess_line_error: Option<String>,
+    /// Share code typed to import the editors of another puzzle
+    share_code: String,
+    share_code_error: Option<String>,
+    /// Letters typed for the row of tiles
+    tile_guess: String,
+    /// Letters of the row of tiles and their colors, empty until the letters are submitted
+    tiles: Vec<(char, Feedback)>,
+    page: usize,
+    history: Vec<FilterSnapshot>,
+    /// Vertical scroll offset of the results, restored when they change
+    results_offset: f32,
+    /// Time of the last edit not filtered yet
+    filter_edited_at: Option<Instant>,
+    /// Number of words removed by the last filtering, and when it happened
+    removed_flash: Option<(usize, Instant)>,
 }
 
 pub struct App {
     words: Vec<String>,
-    filtered_words: Vec<String>,
+    /// The puzzles solved side by side, sharing the word lists and the preferences
+    puzzles: Vec<PuzzleState>,
+    /// Index of the puzzle the last message came from
+    active_puzzle: usize,
+    /// Words counted as common, those of the common list up to the rank threshold
     sorted_common_words: Vec<String>,
     common_words: HashSet<String>,
-    position_content: [Content; 5],
-    including_content: Content,
-    excluding_content: Content,
+    /// Every word of the common list, in its order, whatever their rank
+    common_list: Vec<String>,
+    /// Frequency ranks of the common list, empty when it has none
+    common_ranks: HashMap<String, u32>,
+    /// Highest rank of the ranked words counted as common
+    rank_threshold: u32,
+    maybe_words: HashSet<String>,
+    /// Lowercase answers of past puzzles
+    past_answers: HashSet<String>,
+    /// How likely words are to be the answer, from the ranks of the common list, 1 when missing
+    weights: HashMap<String, u32>,
     only_show_common: bool,
+    use_entropy_suggestion: bool,
+    only_consistent_guesses: bool,
+    show_stats: bool,
+    sort_by_score: bool,
+    sort_by_vowels: bool,
+    hide_guessed: bool,
+    /// Tell word tiers apart by hue rather than brightness
+    high_contrast: bool,
+    /// Whether the panel of preferences is expanded
+    show_settings: bool,
+    /// Whether words the heuristic deems unlikely answers are hidden
+    only_plausible: bool,
+    /// Whether the words of the never answer list are left out of the results
+    hide_maybe_words: bool,
+    hide_past_answers: bool,
+    results_display: ResultsDisplay,
+    /// Whether the results show the words that don't match instead, to check the exclusions
+    show_rejected: bool,
+    copied_word: Option<String>,
+    theme: Theme,
+    /// Whether guesses are entered as a row of tiles instead of the editors
+    tile_mode: bool,
+    saved_state: PersistedState,
+    /// Whether the state is saved when it changes, which an app built for tests doesn't do
+    persist_state: bool,
+    status: Option<String>,
+    word_list_path: String,
+    window_size: Size,
+    /// Position of the window, unknown until it's moved
+    window_position: Option<Point>,
+    columns: usize,
+    load_error: Option<String>,
+    /// Where the word list was read or downloaded from
+    words_source: String,
 }
 
 impl App {
-    pub fn new() -> Self {
-        const ALL_WORDS_FILE_PATH: &str = "data/all_words.csv";
-        const COMMON_WORDS_FILE_PATH: &str = "data/common_words.csv";
-
-        // Load word list
-        let mut all_word_file = File::open(ALL_WORDS_FILE_PATH)
-            .expect("Can't find word list at [{ALL_WORDS_FILE_PATH}]");
-        // Read file
-        let mut text = String::new();
-        all_word_file
-            .read_to_string(&mut text)
-            .expect("Failed to read string from file.");
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        all_words_path: Option<&str>,
+        common_words_path: Option<&str>,
+        maybe_words_path: Option<&str>,
+        past_answers_path: Option<&str>,
+        all_words_url: Option<&str>,
+        common_words_url: Option<&str>,
+        word_list: Option<&NamedWordList>,
+        word_length: Option<usize>,
+        window_size: Size,
+        window_position: Option<Point>,
+    ) -> Self {
+        let mut load_error = None;
 
-        // Extract words
-        let mut words = Vec::with_capacity(14294);
-        for (index, word) in text.lines().enumerate() {
-            if word.len() != 5 {
-                panic!(
-                    "Invalid word in during word exctraction: At line [{}], the word [{word}] wasn't exacly five characters in length",
-                    index + 1
-                )
-            }
-            words.push(word.to_string());
+        let words_source = match (word_list, all_words_url) {
+            (Some(word_list), _) => format!("the {} preset", word_list.name),
+            (None, Some(url)) => url.to_string(),
+            (None, None) => words::word_list_location(
+                all_words_path,
+                words::DEFAULT_ALL_WORDS_FILE_PATH,
+                "the built-in list",
+            ),
+        };
+
+        // Load word list, downloading it when given a URL or a preset to download
+        let text = match (word_list, all_words_url) {
+            (Some(word_list), _) => word_list.all.load(),
+            (None, Some(url)) => words::fetch_word_file(url),
+            (None, None) => words::read_word_list(
+                all_words_path,
+                words::DEFAULT_ALL_WORDS_FILE_PATH,
+                words::EMBEDDED_ALL_WORDS,
+            ),
         }
+        .unwrap_or_else(|error| {
+            load_error = Some(error.to_string());
+            String::new()
+        });
+
+        // A JSON word list carries both tiers, detected from the extension of its path or URL
+        let json_word_lists = all_words_url
+            .or(all_words_path)
+            .filter(|source| words::is_json_path(source))
+            .and_then(|_| {
+                words::parse_json_word_lists(&text, word_length)
+                    .map_err(|error| load_error.get_or_insert(error.to_string()))
+                    .ok()
+            });
+
+        // Detect word length from the first word unless given
+        let word_length = word_length
+            .or_else(|| json_word_lists.as_ref()?.length)
+            .or_else(|| words::detect_word_length(&text))
+            .unwrap_or(words::DEFAULT_WORD_LENGTH);
+
+        // Extract words
+        let mut words = match &json_word_lists {
+            Some(word_lists) => word_lists.all.clone(),
+            None => words::parse_word_file(&text, word_length).unwrap_or_else(|error| {
+                load_error.get_or_insert(error.to_string());
+                Vec::new()
+            }),
+        };
 
         /* Mark common words */
-        // Load word list
-        let mut common_word_file = File::open(COMMON_WORDS_FILE_PATH)
-            .expect("Can't find word list at [{COMMON_WORDS_FILE_PATH}]");
-        // Read file
-        let mut text = String::new();
-        common_word_file
-            .read_to_string(&mut text)
-            .expect("Failed to read string from file.");
-
-        // Extract common words
-        let mut common_words = HashSet::with_capacity(3240);
-        let mut sorted_common_words = Vec::with_capacity(3240);
-        for (index, word) in text.lines().enumerate() {
-            if word.len() != 5 {
-                panic!(
-                    "Invalid word in during word exctraction: At line [{}], the word [{word}] wasn't exacly five characters in length",
-                    index + 1
-                )
+        let has_common_words_source = common_words_path.is_some() || common_words_url.is_some();
+        let (mut sorted_common_words, ranks) = match json_word_lists {
+            // The common list of the JSON file, unless another one is given
+            Some(word_lists) if !has_common_words_source => (word_lists.common, HashMap::new()),
+            _ => {
+                // Load word list
+                // A preset brings its own common words, unless another list is given
+                let text = match (common_words_url, word_list) {
+                    (Some(url), _) => words::fetch_word_file(url),
+                    (None, Some(word_list)) if common_words_path.is_none() => word_list
+                        .common
+                        .map_or(Ok(String::new()), words::WordSource::load),
+                    _ => words::read_word_list(
+                        common_words_path,
+                        words::DEFAULT_COMMON_WORDS_FILE_PATH,
+                        words::EMBEDDED_COMMON_WORDS,
+                    ),
+                }
+                .unwrap_or_else(|error| {
+                    load_error.get_or_insert(error.to_string());
+                    String::new()
+                });
+
+                // Extract common words, and their frequency ranks when the list has them
+                words::parse_ranked_word_file(&text, word_length).unwrap_or_else(|error| {
+                    load_error.get_or_insert(error.to_string());
+                    Default::default()
+                })
             }
-            common_words.insert(word.to_string());
-            sorted_common_words.push(word.to_string());
+        };
+        // Repeated words would show up twice in the results
+        let duplicate_count =
+            words::dedupe_words(&mut words) + words::dedupe_words(&mut sorted_common_words);
+        let mut status = [
+            (duplicate_count > 0).then(|| format!("Removed {duplicate_count} duplicate words")),
+            Self::merge_missing_common_words(&mut words, &sorted_common_words),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(|status, other_status| format!("{status}. {other_status}"));
+
+        // Load the optional never answer words
+        let maybe_words = maybe_words_path
+            .map(|path| {
+                words::load_words(path, word_length).unwrap_or_else(|error| {
+                    load_error.get_or_insert(error.to_string());
+                    Vec::new()
+                })
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+
+        // Load the optional past answers, a missing file only being worth a warning
+        let past_answers = past_answers_path
+            .map(|path| match words::load_words(path, word_length) {
+                Ok(past_answers) => past_answers
+                    .into_iter()
+                    .map(|word| word.to_lowercase())
+                    .collect(),
+                Err(error) => {
+                    status = Some(error.to_string());
+                    HashSet::new()
+                }
+            })
+            .unwrap_or_default();
+
+        let mut app = Self {
+            maybe_words,
+            past_answers,
+            persist_state: true,
+            status,
+            window_size,
+            window_position,
+            load_error,
+            words_source,
+            ..Self::with_words(words, sorted_common_words, ranks, word_length)
+        };
+
+        // Restore the previous session
+        if let Some(state) = PersistedState::load() {
+            app.restore(&state);
+            app.saved_state = state;
         }
 
         // Init filtered words
-        let filtered_words = words.clone();
+        app.update_filtered_words(0);
+
+        app
+    }
+
+    /// An app filtering `words` with the default preferences, `sorted_common_words` being ranked
+    /// by `ranks`, which doesn't restore or save the state
+    fn with_words(
+        words: Vec<String>,
+        sorted_common_words: Vec<String>,
+        ranks: HashMap<String, u32>,
+        word_length: usize,
+    ) -> Self {
+        let weights = words::rank_weights(&ranks);
+        let common_words = sorted_common_words.iter().cloned().collect();
+        // Count every ranked word as common until the threshold is lowered
+        let rank_threshold = ranks.values().copied().max().unwrap_or(0);
 
         Self {
             words,
-            filtered_words,
+            puzzles: vec![PuzzleState::new(word_length)],
+            active_puzzle: 0,
             common_words,
+            common_list: sorted_common_words.clone(),
+            common_ranks: ranks,
+            rank_threshold,
+            maybe_words: HashSet::new(),
+            past_answers: HashSet::new(),
+            weights,
             sorted_common_words,
-            position_content: [
-                Content::new(),
-                Content::new(),
-                Content::new(),
-                Content::new(),
-                Content::new(),
-            ],
-            including_content: Content::new(),
-            excluding_content: Content::new(),
             only_show_common: false,
+            use_entropy_suggestion: false,
+            only_consistent_guesses: false,
+            show_stats: false,
+            sort_by_score: false,
+            sort_by_vowels: false,
+            hide_guessed: true,
+            high_contrast: false,
+            show_settings: false,
+            only_plausible: false,
+            hide_maybe_words: false,
+            hide_past_answers: false,
+            results_display: ResultsDisplay::default(),
+            show_rejected: false,
+            copied_word: None,
+            theme: Theme::Light,
+            tile_mode: false,
+            saved_state: PersistedState::default(),
+            persist_state: false,
+            status: None,
+            word_list_path: String::new(),
+            window_size: INITIAL_WINDOW_SIZE,
+            window_position: None,
+            columns: DEFAULT_COLUMNS,
+            load_error: None,
+            words_source: words::DEFAULT_ALL_WORDS_FILE_PATH.to_string(),
         }
     }
 
@@ -138,221 +582,2949 @@ impl App {
         }
     }
 
-    pub fn view(&self) -> Element<'_, Message> {
-        let position = column![
-            text!("Position"),
-            row![
-                text_editor(&self.position_content[0])
-                    .on_action(|action| Message::PositionEditAction(0, action)),
-                text_editor(&self.position_content[1])
-                    .on_action(|action| Message::PositionEditAction(1, action)),
-                text_editor(&self.position_content[2])
-                    .on_action(|action| Message::PositionEditAction(2, action)),
-                text_editor(&self.position_content[3])
-                    .on_action(|action| Message::PositionEditAction(3, action)),
-                text_editor(&self.position_content[4])
-                    .on_action(|action| Message::PositionEditAction(4, action)),
-            ]
-        ];
+    fn maybe_word_badge_style(theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
 
-        let including = column![
-            text!("Including").center(),
-            text_editor(&self.including_content).on_action(Message::IncludingEditAction),
-        ];
+        Style {
+            text_color: Some(palette.background.strong.color),
+            background: Some(palette.background.base.color.into()),
+            border: rounded(15).color(palette.background.weak.color).width(1),
+            ..Style::default()
+        }
+    }
 
-        let excluding = column![
-            text!("Excluding"),
-            text_editor(&self.excluding_content).on_action(Message::ExcludingEditAction)
-        ];
+    fn high_contrast_common_word_badge_style(theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
 
-        let word_lines = self.filtered_words.chunks(10).map(|word_line| {
-            row(word_line.iter().map(|word| {
-                let mut badge = container(text(word)).padding(Padding {
-                    top: 3.0,
-                    right: 10.0,
-                    bottom: 3.0,
-                    left: 10.0,
-                });
-                badge = if self.common_words.contains(word) {
-                    badge.style(Self::common_word_badge_style)
-                } else {
-                    badge.style(Self::rare_word_badge_style)
-                };
-                badge.into()
-            }))
-            .spacing(10)
-            .clip(true)
-            .into()
-        });
+        Style {
+            text_color: Some(palette.primary.strong.text),
+            background: Some(palette.primary.strong.color.into()),
+            border: rounded(15),
+            ..Style::default()
+        }
+    }
 
-        let words_view = column(word_lines).spacing(10).width(Fill);
+    fn high_contrast_rare_word_badge_style(theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
 
-        let words_scrollable = container(scrollable(words_view).width(Fill)).padding(Padding {
-            top: 10.0,
-            right: 10.0,
-            bottom: 10.0,
-            left: 0.0,
-        });
+        Style {
+            text_color: Some(palette.background.strong.text),
+            background: Some(palette.background.strong.color.into()),
+            border: rounded(15),
+            ..Style::default()
+        }
+    }
 
-        let common_word_toggle = toggler(self.only_show_common)
-            .on_toggle(|_| Message::ToggleCommonWords)
-            .label("Only show common")
-            .spacing(10)
-            .text_line_height(LineHeight::Absolute(iced::Pixels(50.0)))
-            .width(Fill);
+    fn high_contrast_maybe_word_badge_style(theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
 
-        let reset_button = button(text("RESET").width(Fill).center())
-            .on_press(Message::Reset)
-            .width(Fill);
+        Style {
+            text_color: Some(palette.background.base.text),
+            background: Some(palette.background.base.color.into()),
+            border: rounded(15).color(palette.warning.strong.color).width(2),
+            ..Style::default()
+        }
+    }
 
-        let view: Element<'_, Message> = container(
-            row![
-                column![
-                    position,
-                    including,
-                    excluding,
-                    common_word_toggle,
-                    space().height(Fill),
-                    reset_button
-                ]
-                .spacing(10)
-                .width(Length::Fixed(250.0))
-                .padding(10),
-                words_scrollable
-            ]
-            .spacing(10),
-        )
-        .width(Fill)
-        .height(Fill)
-        .into();
+    fn word_badge_style(tier: WordTier, high_contrast: bool) -> fn(&Theme) -> Style {
+        match (tier, high_contrast) {
+            (WordTier::Common, false) => Self::common_word_badge_style,
+            (WordTier::Rare, false) => Self::rare_word_badge_style,
+            (WordTier::Maybe, false) => Self::maybe_word_badge_style,
+            (WordTier::Common, true) => Self::high_contrast_common_word_badge_style,
+            (WordTier::Rare, true) => Self::high_contrast_rare_word_badge_style,
+            (WordTier::Maybe, true) => Self::high_contrast_maybe_word_badge_style,
+        }
+    }
 
-        view // .explain(Color::from_rgb(1.0, 0.0, 0.0))
+    fn word_tier(&self, word: &str) -> WordTier {
+        if self.common_words.contains(word) {
+            WordTier::Common
+        } else if self.maybe_words.contains(word) {
+            WordTier::Maybe
+        } else {
+            WordTier::Rare
+        }
     }
 
-    pub fn update(&mut self, message: Message) {
-        match message {
-            Message::PositionEditAction(idx, action) => {
-                if idx >= 5 {
-                    return;
-                }
-                match action {
-                    Action::Edit(edit) => match edit {
-                        text_editor::Edit::Insert(character) => {
-                            if character.is_alphabetic() {
-                                // Clear text
-                                self.position_content[idx] = Content::new();
+    /// A thin floating scrollbar, leaving no gutter beside the contents
+    fn thin_scrollbar() -> scrollable::Direction {
+        scrollable::Direction::Vertical(
+            scrollable::Scrollbar::new()
+                .width(6)
+                .scroller_width(6)
+                .margin(2),
+        )
+    }
 
-                                // Insert character
-                                self.position_content[idx].perform(Action::Edit(
-                                    text_editor::Edit::Insert(character.to_ascii_uppercase()),
-                                ));
-                            }
-                        }
-                        text_editor::Edit::Backspace | text_editor::Edit::Delete => {
-                            self.position_content[idx] = Content::new();
-                        }
-                        _ => (),
-                    },
-                    _ => self.position_content[idx].perform(action),
-                }
-            }
-            Message::IncludingEditAction(action) => match action {
-                Action::Edit(edit) => match &edit {
-                    text_editor::Edit::Insert(character) => {
-                        if character.is_alphabetic() && self.including_content.text().len() < 5 {
-                            self.including_content.perform(Action::Edit(
-                                text_editor::Edit::Insert(character.to_ascii_uppercase()),
-                            ));
-                        }
-                    }
-                    _ => self.including_content.perform(Action::Edit(edit)),
-                },
-                _ => self.including_content.perform(action),
-            },
-            Message::ExcludingEditAction(action) => match action {
-                Action::Edit(edit) => match &edit {
-                    text_editor::Edit::Insert(character) => {
-                        if !character.is_alphabetic() {
-                            return;
-                        }
-                        let uppercase_charcater = character.to_ascii_uppercase();
-                        if !self.excluding_content.text().contains(uppercase_charcater) {
-                            self.excluding_content.perform(Action::Edit(
-                                text_editor::Edit::Insert(uppercase_charcater),
-                            ));
-                        }
-                    }
-                    _ => self.excluding_content.perform(Action::Edit(edit)),
+    /// A rounded scroller like the badges, only shown when the contents overflow
+    fn thin_scrollable_style(theme: &Theme, status: scrollable::Status) -> scrollable::Style {
+        let palette = theme.extended_palette();
+        let mut style = scrollable::default(theme, status);
+
+        let (is_disabled, is_highlighted) = match status {
+            scrollable::Status::Active {
+                is_vertical_scrollbar_disabled,
+                ..
+            } => (is_vertical_scrollbar_disabled, false),
+            scrollable::Status::Hovered {
+                is_vertical_scrollbar_hovered,
+                is_vertical_scrollbar_disabled,
+                ..
+            } => (
+                is_vertical_scrollbar_disabled,
+                is_vertical_scrollbar_hovered,
+            ),
+            scrollable::Status::Dragged {
+                is_vertical_scrollbar_dragged,
+                is_vertical_scrollbar_disabled,
+                ..
+            } => (
+                is_vertical_scrollbar_disabled,
+                is_vertical_scrollbar_dragged,
+            ),
+        };
+
+        style.vertical_rail = scrollable::Rail {
+            background: None,
+            border: rounded(15),
+            scroller: scrollable::Scroller {
+                background: if is_disabled {
+                    Color::TRANSPARENT.into()
+                } else if is_highlighted {
+                    palette.primary.base.color.into()
+                } else {
+                    palette.background.strong.color.into()
                 },
-                _ => self.excluding_content.perform(action),
+                border: rounded(15),
             },
-            Message::ToggleCommonWords => self.only_show_common = !self.only_show_common,
-            Message::Reset => {
-                self.position_content
-                    .iter_mut()
-                    .for_each(|content| *content = Content::new());
-                self.excluding_content = Content::new();
-                self.including_content = Content::new();
-            }
-        }
+        };
+        style
+    }
 
-        self.update_filtered_words();
+    /// Outlines the selected editor with a thicker border in the primary color
+    fn selected_editor_style(theme: &Theme, status: text_editor::Status) -> text_editor::Style {
+        let mut style = text_editor::default(theme, status);
+        style.border.width = 2.0;
+        style.border.color = theme.extended_palette().primary.strong.color;
+        style
     }
 
-    fn update_filtered_words(&mut self) {
-        self.filtered_words = if self.only_show_common {
-            self.sorted_common_words.clone()
+    /// Style of an editor of a board, outlined when it's the selected one of the active board
+    fn editor_style(
+        &self,
+        puzzle_index: usize,
+        editor: Editor,
+    ) -> fn(&Theme, text_editor::Status) -> text_editor::Style {
+        if puzzle_index == self.active_puzzle
+            && self.puzzles[puzzle_index].selected_editor == Some(editor)
+        {
+            Self::selected_editor_style
         } else {
-            self.words.clone()
-        };
+            text_editor::default
+        }
+    }
 
-        // Filter by position
-        for (index, character) in self
-            .position_content
-            .iter()
-            .map(|content| {
-                content
-                    .text()
-                    .chars()
-                    .next()
-                    .map(|character| character.to_ascii_lowercase())
-            })
-            .enumerate()
-        {
-            if let Some(character) = character {
-                self.filtered_words
-                    .retain(|word| word.chars().nth(index).unwrap_or_else(|| panic!("Can't access character at index [{index}]: the word [{word}], doesn't have five letters.")) == character);
-            }
+    /// Outlines an editor whose contents can't be used in red
+    fn invalid_editor_style(theme: &Theme, status: text_editor::Status) -> text_editor::Style {
+        let mut style = text_editor::default(theme, status);
+        style.border.color = theme.extended_palette().danger.base.color;
+        style
+    }
+
+    fn copied_word_badge_style(theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
+
+        Style {
+            text_color: Some(palette.primary.base.text),
+            background: Some(palette.primary.base.color.into()),
+            border: rounded(15),
+            ..Style::default()
         }
+    }
 
-        // Filter by exclude
-        for character in self
-            .excluding_content
-            .text()
-            .chars()
-            .map(|character| character.to_ascii_lowercase())
-        {
-            self.filtered_words.retain(|word| !word.contains(character))
+    /// Blends the badge background towards the primary color as `intensity` goes from 0 to 1
+    fn letter_frequency_style(intensity: f32) -> impl Fn(&Theme) -> Style {
+        move |theme| {
+            let palette = theme.extended_palette();
+
+            let base_background_color = palette.background.weak.color;
+            let highlight_color = palette.primary.base.color;
+
+            Style {
+                text_color: Some(palette.background.weak.text),
+                background: Some(Background::Color(Color::from_rgb(
+                    base_background_color.r
+                        + (highlight_color.r - base_background_color.r) * intensity,
+                    base_background_color.g
+                        + (highlight_color.g - base_background_color.g) * intensity,
+                    base_background_color.b
+                        + (highlight_color.b - base_background_color.b) * intensity,
+                ))),
+                border: rounded(5),
+                ..Style::default()
+            }
         }
+    }
 
-        // Filter by include
-        // Count character frequency
-        let mut frequency_map: HashMap<char, usize> = HashMap::new();
-        for character in self
-            .including_content
-            .text()
-            .chars()
-            .map(|c| c.to_ascii_lowercase())
-        {
-            frequency_map
-                .entry(character)
-                .and_modify(|frequency| *frequency += 1)
-                .or_insert(1);
+    fn letter_status_style(status: LetterStatus) -> impl Fn(&Theme) -> Style {
+        move |theme| {
+            let palette = theme.extended_palette();
+
+            let (background, text_color) = match status {
+                LetterStatus::Unknown => {
+                    (palette.background.weak.color, palette.background.weak.text)
+                }
+                LetterStatus::Absent => (
+                    palette.background.base.color,
+                    palette.background.strong.color,
+                ),
+                LetterStatus::Present => (palette.warning.base.color, palette.warning.base.text),
+                LetterStatus::Correct => (palette.success.base.color, palette.success.base.text),
+            };
+
+            Style {
+                text_color: Some(text_color),
+                background: Some(background.into()),
+                border: rounded(5),
+                ..Style::default()
+            }
         }
-        // Filter by frequency
-        for (character, frequency) in frequency_map {
-            self.filtered_words
-                .retain(|word| word.chars().filter(|c| c == &character).count() >= frequency);
+    }
+
+    /// Renders the alphabet colored by what the constraints tell about each letter, striking out
+    /// the letters that can't take any of the unknown positions
+    fn alphabet_bar_view(puzzle: &PuzzleState) -> Element<'_, Message> {
+        let impossible_letters = puzzle
+            .applied_constraints
+            .as_ref()
+            .map(|constraints| constraints.impossible_letters(puzzle.position_content.len()))
+            .unwrap_or_default();
+
+        row(('a'..='z').map(|letter| {
+            let status = puzzle
+                .applied_constraints
+                .as_ref()
+                .map_or(LetterStatus::Unknown, |constraints| {
+                    constraints.letter_status(letter)
+                });
+            // Green letters stay readable, a capped count doesn't make them any less found
+            let is_struck = status != LetterStatus::Correct && impossible_letters.contains(&letter);
+
+            container(rich_text::<(), _, _, _>([span(
+                letter.to_ascii_uppercase().to_string(),
+            )
+            .strikethrough(is_struck)]))
+            .center_x(Fill)
+            .padding(3)
+            .style(Self::letter_status_style(status))
+            .into()
+        }))
+        .spacing(3)
+        .into()
+    }
+
+    /// Lists the letters no guess tested yet, worth probing to cover more of the alphabet, once a
+    /// guess was played
+    fn untried_letters_view(puzzle: &PuzzleState) -> Option<Text<'_>> {
+        if puzzle.tried_letters.is_empty() {
+            return None;
         }
+
+        let untried_letters: Vec<String> = ('a'..='z')
+            .filter(|letter| !puzzle.tried_letters.contains(letter))
+            .map(|letter| letter.to_ascii_uppercase().to_string())
+            .collect();
+        Some(
+            text!("Untried: {}", untried_letters.join(" "))
+                .size(12)
+                .style(Self::dimmed_text_style),
+        )
+    }
+
+    /// Explains where the word list was looked for, when it has no words at all
+    fn empty_dictionary_view(words_source: &str) -> Element<'_, Message> {
+        container(
+            column![
+                text("No dictionary loaded").size(24),
+                text!("No words were found in [{words_source}]."),
+                text!(
+                    "Another file can be given with --all-words, one word per line, \
+                     [{}] being read by default.",
+                    words::DEFAULT_ALL_WORDS_FILE_PATH
+                ),
+                text!(
+                    "When {} is set, it is read from that directory instead.",
+                    words::DATA_DIR_VARIABLE
+                ),
+                text("Another list can be loaded from the word list box of the settings."),
+            ]
+            .spacing(10)
+            .align_x(Center),
+        )
+        .center(Fill)
+        .padding(20)
+        .into()
+    }
+
+    /// Renders a setting as a labeled toggle spanning the settings panel
+    fn setting_toggle(
+        label: &'static str,
+        value: bool,
+        on_toggle: fn(bool) -> Message,
+    ) -> Element<'static, Message> {
+        toggler(value)
+            .on_toggle(on_toggle)
+            .label(label)
+            .spacing(10)
+            .text_line_height(LineHeight::Absolute(iced::Pixels(50.0)))
+            .width(Fill)
+            .into()
+    }
+
+    /// Renders the label of an input box, explaining its Wordle meaning on hover
+    fn help_label(label: &'static str, help: &'static str) -> Element<'static, Message> {
+        tooltip(
+            text!("{label} (?)"),
+            container(text(help).size(12))
+                .padding(5)
+                .max_width(CONTROLS_WIDTH)
+                .style(container::rounded_box),
+            tooltip::Position::Bottom,
+        )
+        .into()
+    }
+
+    /// Renders, for each letter, how many of the filtered words contain it
+    fn letter_stats_view(puzzle: &PuzzleState) -> Element<'_, Message> {
+        let word_count = puzzle.filtered_words.len().max(1) as f32;
+
+        row(('a'..='z').map(|letter| {
+            let count = puzzle
+                .filtered_words
+                .iter()
+                .filter(|word| word.contains(letter))
+                .count();
+
+            container(
+                column![text(letter.to_ascii_uppercase()), text(count).size(10)].align_x(Center),
+            )
+            .width(Fill)
+            .padding(3)
+            .style(Self::letter_frequency_style(count as f32 / word_count))
+            .into()
+        }))
+        .spacing(3)
+        .into()
+    }
+
+    /// Renders the row of tiles: the letters of the guess are typed and submitted, then each tile
+    /// is clicked to cycle its color before committing the guess to the filters
+    fn tile_row_view<'a>(
+        puzzle: &'a PuzzleState,
+        guess_header: Row<'a, Message>,
+        guess_line_error: Option<Text<'a>>,
+    ) -> Element<'a, Message> {
+        let tile_input = text_input("CRANE", &puzzle.tile_guess)
+            .on_input(Message::TileGuessEdited)
+            .on_submit(Message::BuildTiles);
+
+        let tiles = (!puzzle.tiles.is_empty()).then(|| {
+            row(puzzle
+                .tiles
+                .iter()
+                .enumerate()
+                .map(|(index, (character, feedback))| {
+                    button(text(*character).width(Fill).center())
+                        .on_press(Message::CycleTile(index))
+                        .style(match feedback {
+                            Feedback::Gray => button::secondary,
+                            Feedback::Yellow => button::warning,
+                            Feedback::Green => button::success,
+                        })
+                        .width(Fill)
+                        .into()
+                }))
+            .push(button(text("COMMIT")).on_press(Message::CommitTiles))
+            .spacing(5)
+        });
+
+        column![guess_header, tile_input]
+            .push(tiles)
+            .push(guess_line_error)
+            .spacing(5)
+            .into()
+    }
+
+    /// Renders the details of a word: its list, its score among the matching words and which clues
+    /// it satisfies, which matters for pinned words that stopped matching
+    fn word_info_view<'a>(&self, puzzle: &'a PuzzleState, word: &'a str) -> Element<'a, Message> {
+        let tier = match self.word_tier(word) {
+            WordTier::Common => "Common word",
+            WordTier::Rare => "Rare word",
+            WordTier::Maybe => "Never answer word",
+        };
+        let score =
+            solver::PositionalFrequencies::new(&puzzle.filtered_words, &self.weights).score(word);
+        let vowel_count = solver::distinct_vowel_count(word);
+        let clue_checks = puzzle
+            .applied_constraints
+            .as_ref()
+            .map(|constraints| constraints.clue_checks(word))
+            .unwrap_or_default();
+
+        let checks = column(clue_checks.into_iter().map(|(label, is_satisfied)| {
+            if is_satisfied {
+                text!("{label}: satisfied").size(12).style(text::success)
+            } else {
+                text!("{label}: not satisfied").size(12).style(text::danger)
+            }
+            .into()
+        }))
+        .push(
+            puzzle
+                .applied_constraints
+                .is_none()
+                .then(|| text("No clues yet").size(12).style(Self::dimmed_text_style)),
+        );
+
+        container(
+            column![
+                row![
+                    text(word.to_uppercase()).size(20),
+                    space().width(Fill),
+                    button(text("CLOSE"))
+                        .on_press(Message::HideWordInfo)
+                        .style(button::secondary),
+                ]
+                .align_y(Center),
+                text!("{tier}, scoring {score} among the matches").size(12),
+                text!(
+                    "{vowel_count} distinct {}",
+                    if vowel_count == 1 { "vowel" } else { "vowels" }
+                )
+                .size(12),
+                checks,
+            ]
+            .spacing(5),
+        )
+        .padding(10)
+        .width(Fill)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    /// Renders the badge of a word, copying it on click, showing its details on double click and
+    /// pinning it on right click
+    fn word_badge_view<'a>(
+        &'a self,
+        puzzle: &'a PuzzleState,
+        word: &'a String,
+        score: Option<usize>,
+    ) -> Element<'a, Message> {
+        let score = score.map(|score| text(score).size(10).style(Self::dimmed_text_style));
+        let mut badge = container(
+            row![Self::word_letters_view(puzzle, word)]
+                .push(score)
+                .spacing(5)
+                .align_y(Center),
+        )
+        .padding(Padding {
+            top: 3.0,
+            right: 10.0,
+            bottom: 3.0,
+            left: 10.0,
+        });
+        badge = if self.copied_word.as_ref() == Some(word) {
+            badge.style(Self::copied_word_badge_style)
+        } else {
+            badge.style(Self::word_badge_style(
+                self.word_tier(word),
+                self.high_contrast,
+            ))
+        };
+        mouse_area(badge)
+            .on_press(Message::CopyWord(word.clone()))
+            .on_double_click(Message::ShowWordInfo(word.clone()))
+            .on_right_press(Message::TogglePin(word.clone()))
+            .interaction(Interaction::Pointer)
+            .into()
+    }
+
+    /// Renders the words at `indices` as a single wrapped block, like `crane, slate, trace`, dimming
+    /// the words that aren't common and copying a word on click
+    fn words_text_view<'a>(&self, words: &'a [String], indices: &[usize]) -> Element<'a, Message> {
+        let palette = self.theme.extended_palette();
+        let spans = indices.iter().enumerate().flat_map(|(position, index)| {
+            let word = &words[*index];
+            let color = match self.word_tier(word) {
+                WordTier::Common => palette.background.base.text,
+                WordTier::Rare => palette.background.strong.color,
+                WordTier::Maybe => palette.warning.strong.color,
+            };
+            [
+                (position > 0).then(|| span(", ")),
+                Some(span(word.as_str()).color(color).link(word.clone())),
+            ]
+            .into_iter()
+            .flatten()
+        });
+
+        rich_text(spans.collect::<Vec<_>>())
+            .on_link_click(Message::CopyWord)
+            .width(Fill)
+            .into()
+    }
+
+    /// Renders a word letter by letter, coloring letters at a confirmed position as green and
+    /// letters known to be in the word as yellow
+    fn word_letters_view<'a>(puzzle: &'a PuzzleState, word: &str) -> Element<'a, Message> {
+        let constraints = puzzle.applied_constraints.as_ref();
+
+        row(word.chars().enumerate().map(|(index, character)| {
+            let letter = text(character);
+            let is_green = constraints
+                .is_some_and(|constraints| constraints.greens.contains(&(index, character)));
+            let is_yellow = constraints.is_some_and(|constraints| {
+                constraints.include_counts.contains_key(&character)
+                    || constraints
+                        .yellows
+                        .iter()
+                        .any(|(_, yellow_character)| *yellow_character == character)
+            });
+
+            if is_green {
+                letter.style(text::success).into()
+            } else if is_yellow {
+                letter.style(text::warning).into()
+            } else {
+                letter.into()
+            }
+        }))
+        .into()
+    }
+
+    fn dimmed_text_style(theme: &Theme) -> text::Style {
+        text::Style {
+            color: Some(theme.extended_palette().background.strong.color),
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if let Some(load_error) = &self.load_error {
+            return container(text(load_error).center())
+                .center(Fill)
+                .padding(20)
+                .into();
+        }
+
+        // Each board routes its messages through its index, so it's written as if it was alone
+        let view: Element<'_, Message> = row((0..self.puzzles.len()).map(|puzzle_index| {
+            self.puzzle_view(puzzle_index)
+                .map(move |message| Message::Puzzle(puzzle_index, Box::new(message)))
+        }))
+        .width(Fill)
+        .height(Fill)
+        .into();
+
+        view // .explain(Color::from_rgb(1.0, 0.0, 0.0))
+    }
+
+    /// Renders the controls and the results of a board, along with the shared preferences for
+    /// the first one
+    fn puzzle_view(&self, puzzle_index: usize) -> Element<'_, Message> {
+        let puzzle = &self.puzzles[puzzle_index];
+        let is_first_puzzle = puzzle_index == 0;
+
+        // The guess about to be played, red once past the allowed guesses
+        let guess_number = puzzle.guess_count + 1;
+        let guess_header = row![
+            text!("Guess"),
+            space().width(Fill),
+            text!("Guess {guess_number} of {MAX_GUESSES}").style(if guess_number > MAX_GUESSES {
+                text::danger
+            } else {
+                text::default
+            }),
+        ];
+        let guess_line_error = puzzle
+            .guess_line_error
+            .as_ref()
+            .map(|error| text(error).size(12).style(text::danger));
+
+        let puzzle_inputs = if self.tile_mode {
+            Self::tile_row_view(puzzle, guess_header, guess_line_error)
+        } else {
+            column![
+                guess_header,
+                text_input("CRANE:BYGBG", &puzzle.guess_line)
+                    .on_input(Message::GuessLineEdited)
+                    .on_submit(Message::ApplyGuessLine(puzzle.guess_line.clone())),
+            ]
+            .push(guess_line_error)
+            .into()
+        };
+
+        let position = column![
+            Self::help_label(
+                "Position",
+                "Green letters, known to be at this position. Several letters in a box allow any \
+                 of them"
+            ),
+            row(puzzle
+                .position_content
+                .iter()
+                .enumerate()
+                .map(|(index, content)| {
+                    text_editor(content)
+                        .id(Self::position_editor_id(puzzle_index, index))
+                        .on_action(move |action| Message::PositionEditAction(index, action))
+                        .style(self.editor_style(puzzle_index, Editor::Position(index)))
+                        .into()
+                }))
+        ];
+
+        let position_exclude = column![
+            Self::help_label(
+                "Not in position",
+                "Yellow letters, in the word but not at this position"
+            ),
+            row(puzzle
+                .position_exclude_content
+                .iter()
+                .enumerate()
+                .map(|(index, content)| {
+                    text_editor(content)
+                        .on_action(move |action| Message::PositionExcludeAction(index, action))
+                        .style(self.editor_style(puzzle_index, Editor::PositionExclude(index)))
+                        .into()
+                }))
+        ];
+
+        let including = column![
+            Self::help_label(
+                "Including",
+                "Yellow letters, known to be present somewhere. Repeat a letter to require it \
+                 several times, and follow it with ! for exactly that many, like EE!. A . or ? \
+                 stands for any letter, like R.N as a substring"
+            ),
+            text_editor(&puzzle.including_content)
+                .on_action(Message::IncludingEditAction)
+                .wrapping(Wrapping::WordOrGlyph)
+                .max_height(LETTERS_EDITOR_MAX_HEIGHT)
+                .style(self.editor_style(puzzle_index, Editor::Including)),
+        ];
+
+        let include_mode = row![
+            text("Match as"),
+            pick_list(
+                IncludeMode::ALL,
+                Some(puzzle.include_mode),
+                Message::IncludeModeSelected
+            )
+            .width(Fill),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let excluding = column![
+            Self::help_label(
+                "Excluding",
+                "Gray letters, not in the word, or not at a position like 2E 4A for no E at \
+                 position 2 and no A at position 4"
+            ),
+            text_editor(&puzzle.excluding_content)
+                .on_action(Message::ExcludingEditAction)
+                .wrapping(Wrapping::WordOrGlyph)
+                .max_height(LETTERS_EDITOR_MAX_HEIGHT)
+                .style(self.editor_style(puzzle_index, Editor::Excluding))
+        ];
+
+        let search = column![
+            text!("Search"),
+            text_editor(&puzzle.search_content)
+                .on_action(Message::SearchEditAction)
+                .style(self.editor_style(puzzle_index, Editor::Search))
+        ];
+
+        let regex_style: fn(&Theme, text_editor::Status) -> text_editor::Style =
+            if puzzle.invalid_regex {
+                Self::invalid_editor_style
+            } else {
+                self.editor_style(puzzle_index, Editor::Regex)
+            };
+        let regex = column![
+            Self::help_label(
+                "Regex",
+                "A pattern the whole word must match, like .*[aeiou]{3}.* for three vowels in a row"
+            ),
+            text_editor(&puzzle.regex_content)
+                .on_action(Message::RegexEditAction)
+                .style(regex_style)
+        ];
+
+        // Rejected words have no score to sort them by
+        let shown_words = puzzle.shown_words(self.show_rejected);
+        let sort_order = if self.sort_by_vowels {
+            Some(&puzzle.vowel_order)
+        } else {
+            Some(&puzzle.score_order).filter(|_| self.sort_by_score)
+        };
+        let page_indices = if let Some(sort_order) = sort_order.filter(|_| !self.show_rejected) {
+            sort_order
+                .chunks(PAGE_SIZE)
+                .nth(puzzle.page)
+                .unwrap_or_default()
+                .to_vec()
+        } else {
+            (puzzle.page * PAGE_SIZE..shown_words.len().min((puzzle.page + 1) * PAGE_SIZE))
+                .collect()
+        };
+
+        let word_lines = page_indices
+            .chunks(self.visible_columns())
+            .map(|word_line| {
+                row(word_line.iter().map(|index| {
+                    self.word_badge_view(
+                        puzzle,
+                        &shown_words[*index],
+                        (!self.show_rejected).then(|| puzzle.scores[*index]),
+                    )
+                }))
+                .spacing(10)
+                .clip(true)
+                .into()
+            });
+
+        let words_view: Element<'_, Message> = match self.results_display {
+            ResultsDisplay::Badges => column(word_lines).spacing(10).width(Fill).into(),
+            ResultsDisplay::Text => self.words_text_view(shown_words, &page_indices),
+        };
+
+        let match_count = row![if self.show_rejected {
+            text!("{} rejected words", puzzle.rejected_words.len()).style(text::danger)
+        } else if puzzle.filtered_words.is_empty() {
+            text("No matches").style(Self::dimmed_text_style)
+        } else {
+            text!("{} matches", puzzle.filtered_words.len())
+        }]
+        .push(
+            puzzle
+                .removed_flash
+                .map(|(removed_count, _)| text!("-{removed_count}").style(text::danger)),
+        )
+        .spacing(10);
+
+        let suggestion = match &puzzle.suggested_guess {
+            Some((word, method)) => {
+                text!("Suggested ({}): {}", method.label(), word.to_uppercase())
+            }
+            None => text(""),
+        };
+
+        let page_count = puzzle.page_count(self.show_rejected);
+        let pagination = row![
+            button(text("<")).on_press_maybe((puzzle.page > 0).then_some(Message::PrevPage)),
+            text!("Page {} of {}", puzzle.page + 1, page_count),
+            button(text(">"))
+                .on_press_maybe((puzzle.page + 1 < page_count).then_some(Message::NextPage)),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let columns_control = row![
+            text("Columns"),
+            button(text("-"))
+                .on_press_maybe((self.columns > 1).then_some(Message::DecreaseColumns)),
+            text(self.columns),
+            button(text("+")).on_press(Message::IncreaseColumns),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        // Call out the last few candidates instead of leaving them among the badges
+        let answer_banner = match puzzle.filtered_words.as_slice() {
+            [word] => Some(
+                text!("Answer: {}", word.to_uppercase())
+                    .size(28)
+                    .style(text::success),
+            ),
+            words @ [_, _, ..] if words.len() <= 5 => Some(text!(
+                "Likely: {}",
+                words
+                    .iter()
+                    .map(|word| word.to_uppercase())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            _ => None,
+        };
+
+        // The shortlist stays whatever the filters, until unpinned
+        let pinned = (!puzzle.pinned.is_empty()).then(|| {
+            column![
+                text("Pinned, right click to unpin")
+                    .size(12)
+                    .style(Self::dimmed_text_style),
+                row(puzzle
+                    .pinned
+                    .iter()
+                    .map(|word| self.word_badge_view(puzzle, word, None)))
+                .spacing(10)
+                .wrap(),
+            ]
+            .spacing(5)
+        });
+
+        let words_scrollable = container(if self.words.is_empty() {
+            Self::empty_dictionary_view(&self.words_source)
+        } else {
+            column![]
+                .push(answer_banner)
+                .push(pinned)
+                .push(
+                    puzzle
+                        .word_info
+                        .as_ref()
+                        .map(|word| self.word_info_view(puzzle, word)),
+                )
+                .push(row![match_count, space().width(Fill), suggestion])
+                .push(
+                    puzzle
+                        .conflict
+                        .as_ref()
+                        .map(|conflict| text(conflict).style(text::danger)),
+                )
+                .push(self.show_stats.then(|| Self::letter_stats_view(puzzle)))
+                .push(
+                    scrollable(words_view)
+                        .id(Self::results_id(puzzle_index))
+                        .on_scroll(Message::ResultsScrolled)
+                        .direction(Self::thin_scrollbar())
+                        .style(Self::thin_scrollable_style)
+                        .width(Fill)
+                        .height(Fill),
+                )
+                .push(row![pagination, space().width(Fill), columns_control])
+                .push(Self::alphabet_bar_view(puzzle))
+                .push(Self::untried_letters_view(puzzle))
+                .spacing(10)
+                .into()
+        })
+        .padding(Padding {
+            top: 10.0,
+            right: 10.0,
+            bottom: 10.0,
+            left: 0.0,
+        });
+
+        let common_word_toggle = Self::setting_toggle(
+            "Only show common (F2)",
+            self.only_show_common,
+            Message::ToggleCommonWords,
+        );
+
+        // Only lists with frequency ranks can be made stricter
+        let rank_threshold = self
+            .common_ranks
+            .values()
+            .copied()
+            .max()
+            .filter(|max_rank| *max_rank > 1)
+            .map(|max_rank| {
+                column![
+                    text!("Common up to rank {}", self.rank_threshold),
+                    slider(
+                        1..=max_rank,
+                        self.rank_threshold,
+                        Message::RankThresholdChanged
+                    ),
+                ]
+                .spacing(5)
+            });
+
+        let hard_mode_toggle = Self::setting_toggle(
+            "Hard mode",
+            self.only_consistent_guesses,
+            Message::ToggleHardMode,
+        );
+
+        let entropy_suggestion_toggle = Self::setting_toggle(
+            "Entropy suggestion",
+            self.use_entropy_suggestion,
+            Message::ToggleEntropySuggestion,
+        );
+
+        let stats_toggle =
+            Self::setting_toggle("Letter stats", self.show_stats, Message::ToggleStats);
+
+        let sort_by_score_toggle =
+            Self::setting_toggle("Sort by score", self.sort_by_score, Message::SortByScore);
+
+        let sort_by_vowels_toggle = Self::setting_toggle(
+            "Sort by most distinct vowels",
+            self.sort_by_vowels,
+            Message::SortByVowels,
+        );
+
+        let hide_guessed_toggle = Self::setting_toggle(
+            "Hide guessed words",
+            self.hide_guessed,
+            Message::ToggleHideGuessed,
+        );
+
+        let high_contrast_toggle = Self::setting_toggle(
+            "High contrast badges",
+            self.high_contrast,
+            Message::ToggleHighContrast,
+        );
+
+        let only_plausible_toggle = Self::setting_toggle(
+            "Hide unlikely answers (heuristic)",
+            self.only_plausible,
+            Message::ToggleOnlyPlausible,
+        );
+
+        let hide_maybe_words_toggle = Self::setting_toggle(
+            "Hide never answer words",
+            self.hide_maybe_words,
+            Message::ToggleHideMaybeWords,
+        );
+
+        let tile_mode_toggle = Self::setting_toggle(
+            "Enter guesses as tiles",
+            self.tile_mode,
+            Message::ToggleTileMode,
+        );
+
+        let hide_past_answers_toggle = Self::setting_toggle(
+            "Hide past answers",
+            self.hide_past_answers,
+            Message::ToggleHidePastAnswers,
+        );
+
+        let text_results_toggle = Self::setting_toggle(
+            "Results as text",
+            self.results_display == ResultsDisplay::Text,
+            Message::ToggleTextResults,
+        );
+
+        let show_rejected_toggle = Self::setting_toggle(
+            "Show rejected words instead",
+            self.show_rejected,
+            Message::ToggleShowRejected,
+        );
+
+        let theme_button = button(text!("THEME: {}", self.theme).width(Fill).center())
+            .on_press(Message::CycleTheme)
+            .width(Fill);
+
+        let puzzles_control = row![
+            text("Boards"),
+            button(text("-"))
+                .on_press_maybe((self.puzzles.len() > 1).then_some(Message::RemovePuzzle)),
+            text(self.puzzles.len()),
+            button(text("+"))
+                .on_press_maybe((self.puzzles.len() < MAX_PUZZLES).then_some(Message::AddPuzzle)),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let word_list = column![
+            text!("Word list"),
+            text_input("path/to/words.csv", &self.word_list_path)
+                .on_input(Message::WordListPathEdited)
+                .on_submit(Message::ReloadWords(self.word_list_path.clone())),
+        ];
+
+        let history = column![
+            text!("History"),
+            scrollable(
+                column(
+                    puzzle
+                        .history
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .map(|(index, snapshot)| {
+                            button(
+                                text!("{}: {} matches", snapshot.label, snapshot.match_count)
+                                    .size(12)
+                                    .width(Fill),
+                            )
+                            .on_press(Message::RestoreSnapshot(index))
+                            .style(button::text)
+                            .padding(2)
+                            .width(Fill)
+                            .into()
+                        })
+                )
+                .width(Fill)
+            )
+            .height(Length::Fixed(100.0)),
+        ];
+
+        let export_button = button(text("EXPORT").width(Fill).center())
+            .on_press(Message::ExportResults)
+            .width(Fill);
+
+        let copy_all_button = button(text("COPY ALL").width(Fill).center())
+            .on_press(Message::CopyAllResults)
+            .width(Fill);
+
+        // The shareable grid of the guesses played so far
+        let copy_emoji_grid_button = button(text("COPY EMOJI GRID").width(Fill).center())
+            .on_press_maybe((!puzzle.played_guesses.is_empty()).then_some(Message::CopyEmojiGrid))
+            .width(Fill);
+
+        // The editors as a code, for someone else to continue from this point
+        let copy_share_code_button = button(text("COPY SHARE CODE").width(Fill).center())
+            .on_press(Message::CopyStateString)
+            .width(Fill);
+        let share_code_error = puzzle
+            .share_code_error
+            .as_ref()
+            .map(|error| text(error).size(12).style(text::danger));
+        let import_share_code = column![
+            text_input(
+                "Share code, like A...E/.R.../L/RST_2E/1/0",
+                &puzzle.share_code
+            )
+            .on_input(Message::ShareCodeEdited)
+            .on_submit(Message::ImportShareCode),
+        ]
+        .push(share_code_error)
+        .spacing(5);
+
+        let status = self
+            .status
+            .as_ref()
+            .filter(|_| is_first_puzzle)
+            .map(|status| text(status).size(12).style(Self::dimmed_text_style));
+
+        let clear_filters_button = button(text("CLEAR FILTERS").width(Fill).center())
+            .on_press(Message::ClearFilters)
+            .width(Fill);
+
+        let new_puzzle_button = button(text("NEW PUZZLE").width(Fill).center())
+            .on_press(Message::NewPuzzle)
+            .style(button::danger)
+            .width(Fill);
+
+        let summary_bar = container(
+            text(self.constraints_summary(puzzle))
+                .size(12)
+                .style(Self::dimmed_text_style),
+        )
+        .padding(Padding {
+            top: 0.0,
+            right: 10.0,
+            bottom: 5.0,
+            left: 10.0,
+        });
+
+        // Preferences rather than puzzle inputs, out of the way unless asked for
+        let settings_button = button(
+            text(if self.show_settings {
+                "HIDE SETTINGS"
+            } else {
+                "SHOW SETTINGS"
+            })
+            .width(Fill)
+            .center(),
+        )
+        .on_press(Message::ToggleSettings)
+        .style(button::secondary)
+        .width(Fill);
+        let settings = (is_first_puzzle && self.show_settings).then(|| {
+            column![tile_mode_toggle, common_word_toggle]
+                .push(rank_threshold)
+                .push(hard_mode_toggle)
+                .push(entropy_suggestion_toggle)
+                .push(stats_toggle)
+                .push(sort_by_score_toggle)
+                .push(sort_by_vowels_toggle)
+                .push(text_results_toggle)
+                .push(show_rejected_toggle)
+                .push(hide_guessed_toggle)
+                .push(high_contrast_toggle)
+                .push(only_plausible_toggle)
+                .push(hide_maybe_words_toggle)
+                .push(hide_past_answers_toggle)
+                .push(puzzles_control)
+                .push(theme_button)
+                .push(word_list)
+                .spacing(10)
+        });
+
+        // The inputs scroll once they grow taller than the window, like the editors filling up late
+        // in a puzzle, keeping the buttons below them in view
+        let inputs = scrollable(
+            column![puzzle_inputs]
+                .push((!self.tile_mode).then(|| {
+                    column![
+                        position,
+                        position_exclude,
+                        including,
+                        include_mode,
+                        excluding
+                    ]
+                    .spacing(10)
+                }))
+                .push(search)
+                .push(regex)
+                .push(is_first_puzzle.then_some(settings_button))
+                .push(settings)
+                .push(history)
+                .spacing(10)
+                .padding(Padding {
+                    top: 10.0,
+                    right: 10.0,
+                    bottom: 0.0,
+                    left: 10.0,
+                }),
+        )
+        .direction(Self::thin_scrollbar())
+        .style(Self::thin_scrollable_style)
+        .height(Fill);
+
+        // The preferences and the puzzle wide buttons are shared, they only show on the first board
+        container(column![
+            row![
+                column![
+                    inputs,
+                    column![]
+                        .push(status)
+                        .push(row![export_button, copy_all_button].spacing(10))
+                        .push(row![copy_emoji_grid_button, copy_share_code_button].spacing(10))
+                        .push(import_share_code)
+                        .push(clear_filters_button)
+                        .push(is_first_puzzle.then(|| space().height(10)))
+                        .push(is_first_puzzle.then_some(new_puzzle_button))
+                        .spacing(10)
+                        .padding(Padding {
+                            top: 0.0,
+                            right: 10.0,
+                            bottom: 10.0,
+                            left: 10.0,
+                        }),
+                ]
+                .spacing(10)
+                .width(Length::Fixed(CONTROLS_WIDTH)),
+                words_scrollable
+            ]
+            .spacing(10)
+            .height(Fill),
+            summary_bar,
+        ])
+        .width(Fill)
+        .height(Fill)
+        .into()
+    }
+
+    /// Summarizes the editors and filters in a line, like `Greens: _A__E  Include: RL  Common-only`
+    fn constraints_summary(&self, puzzle: &PuzzleState) -> String {
+        let greens: String = puzzle
+            .position_content
+            .iter()
+            .map(|content| match content.text().as_str() {
+                "" => "_".to_string(),
+                letter if letter.chars().count() == 1 => letter.to_string(),
+                letters => format!("[{letters}]"),
+            })
+            .collect();
+        let yellows: String = puzzle
+            .position_exclude_content
+            .iter()
+            .map(|content| content.text())
+            .collect();
+        let search = puzzle.search_content.text();
+
+        let mut parts = vec![format!("Greens: {greens}")];
+        if !yellows.is_empty() {
+            parts.push(format!("Yellows: {yellows}"));
+        }
+        if !puzzle.including_content.text().is_empty() {
+            parts.push(format!(
+                "Include: {} ({})",
+                puzzle.including_content.text(),
+                puzzle.include_mode
+            ));
+        }
+        if !puzzle.excluding_content.text().is_empty() {
+            parts.push(format!("Exclude: {}", puzzle.excluding_content.text()));
+        }
+        if !search.trim().is_empty() {
+            parts.push(format!("Search: {}", search.trim()));
+        }
+        let regex = puzzle.regex_content.text();
+        if !regex.trim().is_empty() {
+            parts.push(format!("Regex: {}", regex.trim()));
+        }
+        if self.only_show_common {
+            parts.push("Common-only".to_string());
+        }
+        if self.only_consistent_guesses {
+            parts.push("Hard mode".to_string());
+        }
+        parts.join("  ")
+    }
+
+    /// Number of badges per line of the results, as set by the user unless the window is too narrow
+    fn visible_columns(&self) -> usize {
+        self.columns.min(self.fitting_columns())
+    }
+
+    /// Number of badges fitting on a line of the results of a board at the current window width
+    fn fitting_columns(&self) -> usize {
+        // Besides the controls, leave room for the paddings, spacings and scrollbar around results
+        let board_width = self.window_size.width / self.puzzles.len() as f32;
+        let results_width = board_width - CONTROLS_WIDTH - 40.0;
+        let badge_width =
+            self.word_length() as f32 * CHARACTER_WIDTH + SCORE_WIDTH + BADGE_EXTRA_WIDTH;
+        ((results_width / badge_width) as usize).max(1)
+    }
+
+    /// Length of the words, the same for every board
+    fn word_length(&self) -> usize {
+        self.puzzles[0].position_content.len()
+    }
+
+    fn puzzle_mut(&mut self) -> &mut PuzzleState {
+        &mut self.puzzles[self.active_puzzle]
+    }
+
+    fn results_id(puzzle_index: usize) -> Id {
+        Id::from(format!("results-{puzzle_index}"))
+    }
+
+    fn position_editor_id(puzzle_index: usize, index: usize) -> Id {
+        Id::from(format!("position-{puzzle_index}-{index}"))
+    }
+
+    pub fn subscription(&self) -> Subscription<Message> {
+        let resize = window::resize_events().map(|(_, size)| Message::WindowResized(size));
+        let moves = window::events().filter_map(|(_, event)| match event {
+            window::Event::Moved(position) => Some(Message::WindowMoved(position)),
+            _ => None,
+        });
+        let close_requests = window::close_requests().map(Message::WindowCloseRequested);
+
+        // Check for the end of the typing while edits are waiting to be filtered
+        let recompute_filters = self
+            .puzzles
+            .iter()
+            .any(|puzzle| puzzle.filter_edited_at.is_some())
+            .then(|| time::every(FILTER_DEBOUNCE / 4).map(Message::RecomputeFilters));
+
+        // Check for the end of the removed words flash while it is shown
+        let hide_removed_flash = self
+            .puzzles
+            .iter()
+            .any(|puzzle| puzzle.removed_flash.is_some())
+            .then(|| time::every(REMOVED_FLASH_DURATION / 10).map(Message::HideRemovedFlash));
+
+        // Toggle common words with F2, which typing in the editors doesn't need
+        let shortcuts = keyboard::listen().with(self.only_show_common).filter_map(
+            |(only_show_common, event)| match event {
+                keyboard::Event::KeyPressed {
+                    key: Key::Named(Named::F2),
+                    ..
+                } => Some(Message::ToggleCommonWords(!only_show_common)),
+                _ => None,
+            },
+        );
+
+        Subscription::batch(
+            [
+                Some(resize),
+                Some(moves),
+                Some(close_requests),
+                Some(shortcuts),
+                recompute_filters,
+                hide_removed_flash,
+            ]
+            .into_iter()
+            .flatten(),
+        )
+    }
+
+    pub fn title(&self) -> String {
+        match self.puzzles.as_slice() {
+            [puzzle] => match puzzle.filtered_words.len() {
+                1 => "Wordle Finder — 1 match".to_string(),
+                count => format!("Wordle Finder — {count} matches"),
+            },
+            // Like `Wordle Finder — 3 / 12 matches`, in the order of the boards
+            puzzles => format!(
+                "Wordle Finder — {} matches",
+                puzzles
+                    .iter()
+                    .map(|puzzle| puzzle.filtered_words.len().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" / ")
+            ),
+        }
+    }
+
+    pub fn theme(&self) -> Theme {
+        self.theme.clone()
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        if let Message::Puzzle(puzzle_index, message) = message {
+            if puzzle_index >= self.puzzles.len() {
+                return Task::none();
+            }
+            self.active_puzzle = puzzle_index;
+            return self.update(*message);
+        }
+
+        // The copied word is only highlighted until the next interaction
+        if !matches!(message, Message::CopyWord(_)) {
+            self.copied_word = None;
+        }
+
+        let mut task = Task::none();
+        // What the edit is recorded as in the history of the active board, if it changes the
+        // matching words
+        let mut history_label = None;
+        let is_edit = matches!(
+            message,
+            Message::PositionEditAction(..)
+                | Message::PositionExcludeAction(..)
+                | Message::IncludingEditAction(_)
+                | Message::ExcludingEditAction(_)
+                | Message::SearchEditAction(_)
+                | Message::RegexEditAction(_)
+        );
+        let is_tick = matches!(message, Message::RecomputeFilters(_));
+        // Boards still being typed in, which a tick leaves waiting
+        let mut typing_puzzles = vec![false; self.puzzles.len()];
+        let active_puzzle = self.active_puzzle;
+        if let Some(editor) = Editor::of(&message) {
+            self.puzzle_mut().selected_editor = Some(editor);
+        }
+        match message {
+            Message::PositionEditAction(idx, action) => {
+                let puzzle = self.puzzle_mut();
+                if idx >= puzzle.position_content.len() {
+                    return Task::none();
+                }
+                match Self::perform_position_action(&mut puzzle.position_content[idx], action) {
+                    // Advance to the next position after entering a green letter
+                    PositionFocus::Next if idx + 1 < puzzle.position_content.len() => {
+                        puzzle.selected_editor = Some(Editor::Position(idx + 1));
+                        task = operation::focus(Self::position_editor_id(active_puzzle, idx + 1));
+                    }
+                    // Go back to the previous position
+                    PositionFocus::Previous if idx > 0 => {
+                        puzzle.selected_editor = Some(Editor::Position(idx - 1));
+                        task = operation::focus(Self::position_editor_id(active_puzzle, idx - 1));
+                    }
+                    _ => (),
+                }
+            }
+            Message::PositionExcludeAction(idx, action) => {
+                let puzzle = self.puzzle_mut();
+                if idx >= puzzle.position_exclude_content.len() {
+                    return Task::none();
+                }
+                match action {
+                    Action::Edit(edit) => match &edit {
+                        text_editor::Edit::Insert(character) => {
+                            if !character.is_alphabetic() {
+                                return Task::none();
+                            }
+                            let uppercase_charcater = character.to_ascii_uppercase();
+                            if !puzzle.position_exclude_content[idx]
+                                .text()
+                                .contains(uppercase_charcater)
+                            {
+                                puzzle.position_exclude_content[idx].perform(Action::Edit(
+                                    text_editor::Edit::Insert(uppercase_charcater),
+                                ));
+                            }
+                        }
+                        _ => puzzle.position_exclude_content[idx].perform(Action::Edit(edit)),
+                    },
+                    _ => puzzle.position_exclude_content[idx].perform(action),
+                }
+            }
+            Message::IncludingEditAction(action) => {
+                let puzzle = self.puzzle_mut();
+                match action {
+                    Action::Edit(edit) => match &edit {
+                        text_editor::Edit::Insert(character) => {
+                            puzzle.insert_including_letter(*character)
+                        }
+                        text_editor::Edit::Paste(text) => {
+                            // Paste letter by letter, with the same checks as typing
+                            for character in text.chars() {
+                                puzzle.insert_including_letter(character);
+                            }
+                        }
+                        _ => puzzle.including_content.perform(Action::Edit(edit)),
+                    },
+                    _ => puzzle.including_content.perform(action),
+                }
+            }
+            Message::ExcludingEditAction(action) => {
+                let puzzle = self.puzzle_mut();
+                match action {
+                    Action::Edit(edit) => match &edit {
+                        // Positions and the spaces separating them, like `2E 4A`
+                        text_editor::Edit::Insert(character)
+                            if character.is_ascii_digit() || *character == ' ' =>
+                        {
+                            puzzle.excluding_content.perform(Action::Edit(edit));
+                        }
+                        text_editor::Edit::Insert(character) => {
+                            if !character.is_alphabetic() {
+                                return Task::none();
+                            }
+                            // Compare in uppercase in case the box holds lowercase letters
+                            let uppercase_charcater = character.to_ascii_uppercase();
+                            let (letters, _) =
+                                constraints::parse_excluding(&puzzle.excluding_content.text());
+                            if puzzle.is_typing_excluded_position()
+                                || !letters.contains(uppercase_charcater)
+                            {
+                                puzzle.excluding_content.perform(Action::Edit(
+                                    text_editor::Edit::Insert(uppercase_charcater),
+                                ));
+                            }
+                        }
+                        text_editor::Edit::Paste(_) => {
+                            // Pasted text bypasses the checks above, normalize the whole box
+                            // instead
+                            puzzle.excluding_content.perform(Action::Edit(edit));
+                            puzzle.excluding_content = Content::with_text(
+                                &constraints::normalize_excluding(&puzzle.excluding_content.text()),
+                            );
+                            puzzle
+                                .excluding_content
+                                .perform(Action::Move(text_editor::Motion::DocumentEnd));
+                        }
+                        _ => puzzle.excluding_content.perform(Action::Edit(edit)),
+                    },
+                    _ => puzzle.excluding_content.perform(action),
+                }
+            }
+            Message::SearchEditAction(action) => {
+                // The search is a single line
+                if !matches!(action, Action::Edit(text_editor::Edit::Enter)) {
+                    self.puzzle_mut().search_content.perform(action);
+                }
+            }
+            Message::RegexEditAction(action) => {
+                // The pattern is a single line too
+                if !matches!(action, Action::Edit(text_editor::Edit::Enter)) {
+                    self.puzzle_mut().regex_content.perform(action);
+                }
+            }
+            Message::ToggleCommonWords(only_show_common) => {
+                self.only_show_common = only_show_common
+            }
+            Message::RankThresholdChanged(rank_threshold) => {
+                self.rank_threshold = rank_threshold;
+                self.sorted_common_words =
+                    words::words_up_to_rank(&self.common_list, &self.common_ranks, rank_threshold);
+                self.common_words = self.sorted_common_words.iter().cloned().collect();
+                // Words changed tiers, which the previous results were sorted and filtered by
+                for puzzle in &mut self.puzzles {
+                    puzzle.applied_constraints = None;
+                }
+            }
+            Message::ToggleEntropySuggestion(use_entropy_suggestion) => {
+                self.use_entropy_suggestion = use_entropy_suggestion;
+                for puzzle_index in 0..self.puzzles.len() {
+                    self.update_suggested_guess(puzzle_index);
+                }
+            }
+            Message::ToggleHardMode(only_consistent_guesses) => {
+                self.only_consistent_guesses = only_consistent_guesses;
+                for puzzle_index in 0..self.puzzles.len() {
+                    self.update_suggested_guess(puzzle_index);
+                }
+            }
+            Message::IncludeModeSelected(include_mode) => {
+                self.puzzle_mut().include_mode = include_mode
+            }
+            Message::ToggleStats(show_stats) => self.show_stats = show_stats,
+            Message::ToggleHideGuessed(hide_guessed) => self.hide_guessed = hide_guessed,
+            Message::ToggleHighContrast(high_contrast) => self.high_contrast = high_contrast,
+            Message::ToggleOnlyPlausible(only_plausible) => self.only_plausible = only_plausible,
+            Message::ToggleTextResults(is_text) => {
+                self.results_display = if is_text {
+                    ResultsDisplay::Text
+                } else {
+                    ResultsDisplay::Badges
+                }
+            }
+            Message::ToggleShowRejected(show_rejected) => {
+                self.show_rejected = show_rejected;
+                for puzzle_index in 0..self.puzzles.len() {
+                    self.puzzles[puzzle_index].page = 0;
+                    self.update_rejected_words(puzzle_index);
+                }
+            }
+            Message::ToggleHidePastAnswers(hide_past_answers) => {
+                self.hide_past_answers = hide_past_answers;
+                if hide_past_answers && self.past_answers.is_empty() {
+                    self.status = Some(
+                        "No past answers to hide, give a list with --past-answers".to_string(),
+                    );
+                }
+            }
+            Message::ToggleHideMaybeWords(hide_maybe_words) => {
+                self.hide_maybe_words = hide_maybe_words;
+                if hide_maybe_words && self.maybe_words.is_empty() {
+                    self.status = Some(
+                        "No never answer words to hide, give a list with --maybe-words".to_string(),
+                    );
+                }
+            }
+            Message::SortByScore(sort_by_score) => {
+                self.sort_by_score = sort_by_score;
+                self.puzzles.iter_mut().for_each(|puzzle| puzzle.page = 0);
+            }
+            Message::SortByVowels(sort_by_vowels) => {
+                self.sort_by_vowels = sort_by_vowels;
+                self.puzzles.iter_mut().for_each(|puzzle| puzzle.page = 0);
+            }
+            Message::CopyWord(word) => {
+                task = clipboard::write(word.clone());
+                self.copied_word = Some(word);
+            }
+            Message::GuessLineEdited(guess_line) => self.puzzle_mut().guess_line = guess_line,
+            Message::ApplyGuessLine(guess_line) => {
+                let hard_mode = self.only_consistent_guesses;
+                let puzzle = self.puzzle_mut();
+                match solver::parse_guess_line(&guess_line)
+                    .and_then(|guess| puzzle.play_guess(&guess, hard_mode))
+                {
+                    Ok(()) => {
+                        history_label = Some(guess_line.trim().to_uppercase());
+                        puzzle.guess_line.clear();
+                        puzzle.guess_line_error = None;
+                    }
+                    Err(error) => puzzle.guess_line_error = Some(error),
+                }
+            }
+            Message::ToggleTileMode(tile_mode) => self.tile_mode = tile_mode,
+            Message::TileGuessEdited(tile_guess) => {
+                let puzzle = self.puzzle_mut();
+                puzzle.tile_guess = tile_guess
+                    .chars()
+                    .filter(|character| character.is_alphabetic())
+                    .map(|character| character.to_ascii_uppercase())
+                    .take(puzzle.position_content.len())
+                    .collect();
+            }
+            Message::BuildTiles => {
+                let puzzle = self.puzzle_mut();
+                if puzzle.tile_guess.chars().count() == puzzle.position_content.len() {
+                    // Start gray, the most common color
+                    puzzle.tiles = puzzle
+                        .tile_guess
+                        .chars()
+                        .map(|character| (character, Feedback::Gray))
+                        .collect();
+                    puzzle.guess_line_error = None;
+                } else {
+                    puzzle.guess_line_error = Some(format!(
+                        "The guess must have {} letters",
+                        puzzle.position_content.len()
+                    ));
+                }
+            }
+            Message::CycleTile(index) => {
+                if let Some((_, feedback)) = self.puzzle_mut().tiles.get_mut(index) {
+                    *feedback = match feedback {
+                        Feedback::Gray => Feedback::Yellow,
+                        Feedback::Yellow => Feedback::Green,
+                        Feedback::Green => Feedback::Gray,
+                    };
+                }
+            }
+            Message::CommitTiles => {
+                let hard_mode = self.only_consistent_guesses;
+                let puzzle = self.puzzle_mut();
+                let tiles = puzzle.tiles.clone();
+                match puzzle.play_guess(&tiles, hard_mode) {
+                    Ok(()) => {
+                        // Label it like the guess line would have been
+                        history_label = Some(solver::format_guess_line(&tiles));
+                        puzzle.tile_guess.clear();
+                        puzzle.tiles.clear();
+                        puzzle.guess_line_error = None;
+                    }
+                    Err(error) => puzzle.guess_line_error = Some(error),
+                }
+            }
+            Message::ExportResults => {
+                let filtered_words = &self.puzzles[active_puzzle].filtered_words;
+                let mut contents = format!("# {} matches\n", filtered_words.len());
+                for word in filtered_words {
+                    contents.push_str(word);
+                    contents.push('\n');
+                }
+                self.status = Some(match std::fs::write(EXPORT_FILE_PATH, contents) {
+                    Ok(()) => format!("Exported results to [{EXPORT_FILE_PATH}]"),
+                    Err(error) => format!("Failed to export to [{EXPORT_FILE_PATH}]: {error}"),
+                });
+            }
+            Message::CopyAllResults => {
+                let filtered_words = &self.puzzles[active_puzzle].filtered_words;
+                task = clipboard::write(filtered_words.join("\n"));
+                self.status = Some(format!("Copied {} words", filtered_words.len()));
+            }
+            Message::CopyEmojiGrid => {
+                let played_guesses = &self.puzzles[active_puzzle].played_guesses;
+                task = clipboard::write(solver::emoji_grid(played_guesses));
+                self.status = Some(format!(
+                    "Copied the grid of {} guesses",
+                    played_guesses.len()
+                ));
+            }
+            Message::CopyStateString => {
+                let share_code = self.puzzles[active_puzzle].share_code(self.only_show_common);
+                task = clipboard::write(share_code.to_string());
+                self.status = Some(format!("Copied the share code [{share_code}]"));
+            }
+            Message::ShareCodeEdited(share_code) => self.puzzle_mut().share_code = share_code,
+            Message::ImportShareCode => {
+                let puzzle = self.puzzle_mut();
+                match ShareCode::parse(&puzzle.share_code)
+                    .and_then(|share_code| puzzle.import_share_code(&share_code))
+                {
+                    Ok(only_show_common) => {
+                        puzzle.share_code.clear();
+                        puzzle.share_code_error = None;
+                        self.only_show_common = only_show_common;
+                        history_label = Some("Import".to_string());
+                    }
+                    Err(error) => puzzle.share_code_error = Some(error),
+                }
+            }
+            Message::WordListPathEdited(word_list_path) => self.word_list_path = word_list_path,
+            Message::ReloadWords(path) => match words::load_words(&path, self.word_length()) {
+                Ok(mut words) => {
+                    let duplicate_count = words::dedupe_words(&mut words);
+                    let mut status = format!("Loaded {} words from [{path}]", words.len());
+                    if duplicate_count > 0 {
+                        status.push_str(&format!(", removed {duplicate_count} duplicates"));
+                    }
+                    if let Some(merge_status) =
+                        Self::merge_missing_common_words(&mut words, &self.common_list)
+                    {
+                        status = format!("{status}. {merge_status}");
+                    }
+                    self.status = Some(status);
+                    self.words = words;
+                    self.words_source = path;
+                    // The words changed under the previous results, rebuild them from scratch
+                    for puzzle in &mut self.puzzles {
+                        puzzle.applied_constraints = None;
+                    }
+                }
+                Err(error) => self.status = Some(error.to_string()),
+            },
+            Message::WindowResized(size) => {
+                // Minimizing shrinks the window to nothing, which isn't worth reopening at
+                if persistence::is_valid_window_size((size.width, size.height)) {
+                    self.window_size = size;
+                }
+                // Nothing to filter again, and pending edits must keep waiting. The geometry is
+                // only saved along with the next change, or when the window closes
+                return Task::none();
+            }
+            Message::WindowMoved(position) => {
+                if persistence::is_valid_window_position((position.x, position.y)) {
+                    self.window_position = Some(position);
+                }
+                return Task::none();
+            }
+            Message::WindowCloseRequested(id) => {
+                // Save the geometry, which moving and resizing leave unsaved
+                self.save_state();
+                return window::close(id);
+            }
+            Message::IncreaseColumns => self.columns += 1,
+            Message::DecreaseColumns => self.columns = self.columns.saturating_sub(1).max(1),
+            Message::NextPage => {
+                let show_rejected = self.show_rejected;
+                let puzzle = self.puzzle_mut();
+                puzzle.page = (puzzle.page + 1).min(puzzle.page_count(show_rejected) - 1);
+            }
+            Message::PrevPage => {
+                let puzzle = self.puzzle_mut();
+                puzzle.page = puzzle.page.saturating_sub(1);
+            }
+            Message::RecomputeFilters(now) => {
+                typing_puzzles = self
+                    .puzzles
+                    .iter()
+                    .map(|puzzle| {
+                        puzzle.filter_edited_at.is_some_and(|edited_at| {
+                            now.duration_since(edited_at) < FILTER_DEBOUNCE
+                        })
+                    })
+                    .collect();
+                let has_paused_edits = self
+                    .puzzles
+                    .iter()
+                    .zip(&typing_puzzles)
+                    .any(|(puzzle, is_typing)| puzzle.filter_edited_at.is_some() && !is_typing);
+                if !has_paused_edits {
+                    return Task::none();
+                }
+                history_label = Some("Edit".to_string());
+            }
+            Message::HideRemovedFlash(now) => {
+                for puzzle in &mut self.puzzles {
+                    if puzzle.removed_flash.is_some_and(|(_, flashed_at)| {
+                        now.duration_since(flashed_at) >= REMOVED_FLASH_DURATION
+                    }) {
+                        puzzle.removed_flash = None;
+                    }
+                }
+                // Nothing else changed, and pending edits must keep waiting
+                return Task::none();
+            }
+            Message::ResultsScrolled(viewport) => {
+                self.puzzle_mut().results_offset = viewport.absolute_offset().y;
+                // Scrolling fires for every frame of the movement, which mustn't filter again
+                return Task::none();
+            }
+            Message::RestoreSnapshot(index) => {
+                let puzzle = self.puzzle_mut();
+                if let Some(snapshot) = puzzle.history.get(index).cloned() {
+                    puzzle.restore_snapshot(&snapshot);
+                }
+            }
+            Message::CycleTheme => {
+                self.theme = match self.theme {
+                    Theme::Light => Theme::Dark,
+                    Theme::Dark => Theme::TokyoNight,
+                    _ => Theme::Light,
+                }
+            }
+            Message::ClearFilters => self.puzzle_mut().clear_filters(),
+            Message::ToggleSettings => self.show_settings = !self.show_settings,
+            Message::TogglePin(word) => {
+                let pinned = &mut self.puzzle_mut().pinned;
+                if let Some(index) = pinned.iter().position(|pinned| *pinned == word) {
+                    pinned.remove(index);
+                } else {
+                    pinned.push(word);
+                }
+            }
+            Message::ShowWordInfo(word) => self.puzzle_mut().word_info = Some(word),
+            Message::HideWordInfo => self.puzzle_mut().word_info = None,
+            Message::AddPuzzle => {
+                if self.puzzles.len() < MAX_PUZZLES {
+                    self.puzzles.push(PuzzleState::new(self.word_length()));
+                }
+            }
+            Message::RemovePuzzle => {
+                if self.puzzles.len() > 1 {
+                    self.puzzles.pop();
+                    self.active_puzzle = self.active_puzzle.min(self.puzzles.len() - 1);
+                }
+            }
+            Message::NewPuzzle => {
+                self.new_puzzle();
+                task = Task::batch((0..self.puzzles.len()).map(|puzzle_index| {
+                    operation::scroll_to(
+                        Self::results_id(puzzle_index),
+                        AbsoluteOffset {
+                            x: None,
+                            y: Some(0.0),
+                        },
+                    )
+                }));
+            }
+            Message::Puzzle(..) => unreachable!("board messages are unwrapped above"),
+        }
+
+        if is_edit {
+            // Wait for the typing to pause before filtering
+            self.puzzle_mut().filter_edited_at = Some(Instant::now());
+        } else {
+            let mut tasks = vec![task];
+            for (puzzle_index, is_typing) in typing_puzzles.into_iter().enumerate() {
+                if is_typing {
+                    continue;
+                }
+                // A tick records the paused edits of every board, other messages only concern
+                // the active one
+                let has_edits = self.puzzles[puzzle_index].filter_edited_at.take().is_some();
+                let label = if is_tick {
+                    history_label.clone().filter(|_| has_edits)
+                } else {
+                    history_label
+                        .clone()
+                        .filter(|_| puzzle_index == self.active_puzzle)
+                };
+
+                if self.update_filtered_words(puzzle_index) {
+                    let puzzle = &mut self.puzzles[puzzle_index];
+                    if let Some(label) = label {
+                        let snapshot = puzzle.snapshot(label);
+                        puzzle.history.push(snapshot);
+                        if puzzle.history.len() > HISTORY_LIMIT {
+                            puzzle.history.remove(0);
+                        }
+                    }
+
+                    // Keep the place in the results, the scrollable clamping the offset to the
+                    // bottom of a shorter list
+                    tasks.push(operation::scroll_to(
+                        Self::results_id(puzzle_index),
+                        AbsoluteOffset {
+                            x: None,
+                            y: Some(puzzle.results_offset),
+                        },
+                    ));
+                }
+            }
+            task = Task::batch(tasks);
+        }
+
+        // Save the state whenever it changed
+        self.save_state();
+
+        task
+    }
+
+    /// Saves the state unless it's unchanged since the last save, or the app doesn't persist it
+    fn save_state(&mut self) {
+        let state = self.persisted_state();
+        if self.persist_state && state != self.saved_state {
+            state.save();
+            self.saved_state = state;
+        }
+    }
+
+    /// Performs an action on a position box, which only ever holds distinct uppercase letters, and
+    /// tells where the focus should go
+    fn perform_position_action(content: &mut Content, action: Action) -> PositionFocus {
+        let mut focus = PositionFocus::Stay;
+        match action {
+            Action::Edit(edit) => match edit {
+                // Several letters allow any of them at this position, the normalization below
+                // uppercases them and drops repeated ones
+                text_editor::Edit::Insert(character) if character.is_alphabetic() => {
+                    if content.text().is_empty() {
+                        focus = PositionFocus::Next;
+                    }
+                    content.perform(Action::Edit(edit));
+                }
+                text_editor::Edit::Paste(ref text) if text.chars().any(char::is_alphabetic) => {
+                    if content.text().is_empty() {
+                        focus = PositionFocus::Next;
+                    }
+                    content.perform(Action::Edit(edit));
+                }
+                text_editor::Edit::Backspace if content.text().is_empty() => {
+                    focus = PositionFocus::Previous;
+                }
+                text_editor::Edit::Backspace | text_editor::Edit::Delete => {
+                    content.perform(Action::Edit(edit));
+                }
+                _ => (),
+            },
+            _ => content.perform(action),
+        }
+
+        // Whatever the action did, boxes only hold distinct uppercase letters, which
+        // `position_letters` relies on
+        let letters = content.text();
+        let normalized_letters = constraints::normalize_letter_set(&letters);
+        if normalized_letters != letters {
+            *content = Content::with_text(&normalized_letters);
+            content.perform(Action::Move(text_editor::Motion::DocumentEnd));
+        }
+
+        focus
+    }
+
+    /// The uppercase letters of a position box, which the constraints compare in lowercase
+    fn position_letters(content: &Content) -> Vec<char> {
+        content.text().chars().collect()
+    }
+
+    /// Adds the common words missing from the word list to it, otherwise they would only show up
+    /// with only common words shown, and describes which ones were added
+    fn merge_missing_common_words(
+        words: &mut Vec<String>,
+        sorted_common_words: &[String],
+    ) -> Option<String> {
+        let missing_words = words::merge_missing_words(words, sorted_common_words);
+        match missing_words.as_slice() {
+            [] => None,
+            [word] => Some(format!(
+                "Added the common word [{word}] missing from the word list"
+            )),
+            _ => Some(format!(
+                "Added {} common words missing from the word list, like [{}]",
+                missing_words.len(),
+                missing_words[0]
+            )),
+        }
+    }
+
+    /// Forgets everything about the current puzzle on every board: the filters, the guessed and
+    /// pinned words, the history and the place in the results
+    ///
+    /// Preferences outlive puzzles: the theme, the columns, the word list, the toggles and the
+    /// number of boards are kept.
+    fn new_puzzle(&mut self) {
+        for puzzle in &mut self.puzzles {
+            puzzle.clear_filters();
+            puzzle.guessed.clear();
+            puzzle.guess_count = 0;
+            puzzle.played_guesses.clear();
+            puzzle.tried_letters.clear();
+            puzzle.pinned.clear();
+            puzzle.word_info = None;
+            puzzle.history.clear();
+            puzzle.results_offset = 0.0;
+            puzzle.page = 0;
+        }
+        self.status = None;
+    }
+
+    /// The state kept between launches, only the first board being saved
+    fn persisted_state(&self) -> PersistedState {
+        let puzzle = &self.puzzles[0];
+        PersistedState {
+            positions: puzzle
+                .position_content
+                .iter()
+                .map(|content| content.text())
+                .collect(),
+            position_excludes: puzzle
+                .position_exclude_content
+                .iter()
+                .map(|content| content.text())
+                .collect(),
+            including: puzzle.including_content.text(),
+            excluding: puzzle.excluding_content.text(),
+            only_show_common: self.only_show_common,
+            columns: self.columns,
+            history: puzzle.history.clone(),
+            guessed: {
+                let mut guessed: Vec<String> = puzzle.guessed.iter().cloned().collect();
+                guessed.sort_unstable();
+                guessed
+            },
+            guess_count: puzzle.guess_count,
+            played_guesses: puzzle
+                .played_guesses
+                .iter()
+                .map(|guess| solver::format_guess_line(guess))
+                .collect(),
+            pinned: puzzle.pinned.clone(),
+            show_guessed: !self.hide_guessed,
+            high_contrast: self.high_contrast,
+            hide_past_answers: self.hide_past_answers,
+            window_size: Some((self.window_size.width, self.window_size.height)),
+            window_position: self
+                .window_position
+                .map(|position| (position.x, position.y)),
+        }
+    }
+
+    /// Restores a persisted state in the first board, ignoring positions saved for another word
+    /// length
+    fn restore(&mut self, state: &PersistedState) {
+        let puzzle = &mut self.puzzles[0];
+        if state.positions.len() == puzzle.position_content.len() {
+            puzzle.position_content = state
+                .positions
+                .iter()
+                .map(|text| Content::with_text(&constraints::normalize_letter_set(text)))
+                .collect();
+        }
+        if state.position_excludes.len() == puzzle.position_exclude_content.len() {
+            puzzle.position_exclude_content = state
+                .position_excludes
+                .iter()
+                .map(|text| Content::with_text(text))
+                .collect();
+        }
+        puzzle.including_content = Content::with_text(&state.including);
+        puzzle.excluding_content = Content::with_text(&state.excluding);
+        puzzle.history = state.history.clone();
+        puzzle.guessed = state.guessed.iter().cloned().collect();
+        puzzle.tried_letters = puzzle
+            .guessed
+            .iter()
+            .flat_map(|word| word.chars())
+            .collect();
+        puzzle.guess_count = state.guess_count;
+        puzzle.played_guesses = state
+            .played_guesses
+            .iter()
+            .filter_map(|guess_line| solver::parse_guess_line(guess_line).ok())
+            .collect();
+        puzzle.pinned = state.pinned.clone();
+        self.only_show_common = state.only_show_common;
+        if state.columns > 0 {
+            self.columns = state.columns;
+        }
+        self.hide_guessed = !state.show_guessed;
+        self.high_contrast = state.high_contrast;
+        self.hide_past_answers = state.hide_past_answers;
+    }
+
+    /// Builds the constraints described by the editors of a board
+    fn constraints(&self, puzzle: &PuzzleState) -> Constraints {
+        // Collect green letters by position, boxes with several letters allowing any of them
+        let mut greens = Vec::new();
+        let mut allowed = Vec::new();
+        for (index, content) in puzzle.position_content.iter().enumerate() {
+            let letters = Self::position_letters(content);
+            match letters.as_slice() {
+                [] => (),
+                [character] => greens.push((index, *character)),
+                // Unlike the greens, the allowed letters don't go through the constructor
+                _ => allowed.push((
+                    index,
+                    letters
+                        .iter()
+                        .map(|character| character.to_ascii_lowercase())
+                        .collect(),
+                )),
+            }
+        }
+
+        // Collect letters present but not at this position
+        let yellows = puzzle
+            .position_exclude_content
+            .iter()
+            .enumerate()
+            .flat_map(|(index, content)| {
+                content
+                    .text()
+                    .chars()
+                    .map(move |character| (index, character))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        // Split the excluding box into letters excluded from the whole word or from a position
+        let (excluding, excluded_positions) =
+            constraints::parse_excluding(&puzzle.excluding_content.text());
+        let excluded_positions = excluded_positions
+            .into_iter()
+            .map(|(index, character)| (index, character.to_ascii_lowercase()))
+            .collect();
+
+        Constraints {
+            allowed,
+            excluded_positions,
+            search: puzzle.search_content.text().trim().to_lowercase(),
+            excluded_words: {
+                let mut excluded_words = HashSet::new();
+                if self.hide_guessed {
+                    excluded_words.extend(puzzle.guessed.iter().cloned());
+                }
+                if self.hide_past_answers {
+                    excluded_words.extend(self.past_answers.iter().cloned());
+                }
+                excluded_words
+            },
+            ..Constraints::with_include_mode(
+                greens,
+                yellows,
+                &puzzle.including_content.text(),
+                puzzle.include_mode,
+                &excluding,
+            )
+        }
+    }
+
+    /// Recomputes the `filtered_words` of a board from its current constraints
+    ///
+    /// When the constraints only got stricter since the last call, the previous result is narrowed
+    /// down in place. Otherwise every word of the source list is tested in a single pass, only
+    /// cloning the words that match into a reused buffer. Filtering used to clone the whole source
+    /// list (about 14k strings) before retaining matches, so a full rebuild now allocates as many
+    /// strings as there are matches instead of the whole list.
+    ///
+    /// Returns whether the matching words changed.
+    fn update_filtered_words(&mut self, puzzle_index: usize) -> bool {
+        let puzzle = &self.puzzles[puzzle_index];
+        let constraints = self.constraints(puzzle);
+        let previous_count = puzzle.filtered_words.len();
+
+        // Narrowing a pruned result would miss the words made plausible again by the new stats
+        let regex_text = puzzle.regex_content.text().trim().to_string();
+        let is_narrowing = puzzle.applied_only_show_common == self.only_show_common
+            && puzzle.applied_regex == regex_text
+            && puzzle.applied_hide_maybe_words == self.hide_maybe_words
+            && !self.only_plausible
+            && !puzzle.applied_only_plausible
+            && puzzle
+                .applied_constraints
+                .as_ref()
+                .is_some_and(|applied_constraints| {
+                    constraints.is_stricter_than(applied_constraints)
+                });
+
+        let has_changed = if is_narrowing {
+            // Retaining keeps the order, so the result stays sorted
+            let puzzle = &mut self.puzzles[puzzle_index];
+            constraints::retain_matching(&mut puzzle.filtered_words, &constraints);
+            puzzle.filtered_words.len() != previous_count
+        } else {
+            // Keep the previous result around to detect changes, reusing its allocation for this one
+            let puzzle = &mut self.puzzles[puzzle_index];
+            std::mem::swap(
+                &mut puzzle.filtered_words,
+                &mut puzzle.previous_filtered_words,
+            );
+            let mut filtered_words = std::mem::take(&mut puzzle.filtered_words);
+
+            let source = if self.only_show_common {
+                &self.sorted_common_words
+            } else {
+                &self.words
+            };
+            filtered_words.clear();
+            constraints::extend_matching(&mut filtered_words, source, &constraints);
+            if self.hide_maybe_words {
+                filtered_words.retain(|word| !self.maybe_words.contains(word));
+            }
+
+            // Anchor the pattern to the whole word, ignoring it until it compiles
+            let mut invalid_regex = false;
+            if !regex_text.is_empty() {
+                match Regex::new(&format!("^(?:{regex_text})$")) {
+                    Ok(regex) => filtered_words.retain(|word| regex.is_match(word)),
+                    Err(_) => invalid_regex = true,
+                }
+            }
+
+            // Sort common words first and never answer words last, then the most frequent words
+            // first, then alphabetically
+            filtered_words.sort_by(|word, other_word| {
+                (
+                    self.word_tier(word),
+                    std::cmp::Reverse(self.weights.get(word)),
+                    word,
+                )
+                    .cmp(&(
+                        self.word_tier(other_word),
+                        std::cmp::Reverse(self.weights.get(other_word)),
+                        other_word,
+                    ))
+            });
+
+            if self.only_plausible {
+                let stats = solver::LetterFrequencies::new(&filtered_words);
+                // Never hide every candidate, the heuristic is only a guess
+                if filtered_words
+                    .iter()
+                    .any(|word| solver::is_plausible_answer(word, &stats))
+                {
+                    filtered_words.retain(|word| solver::is_plausible_answer(word, &stats));
+                }
+            }
+
+            let puzzle = &mut self.puzzles[puzzle_index];
+            puzzle.invalid_regex = invalid_regex;
+            puzzle.filtered_words = filtered_words;
+            puzzle.filtered_words != puzzle.previous_filtered_words
+        };
+
+        let puzzle = &mut self.puzzles[puzzle_index];
+        // Explain an empty result rather than leaving it baffling
+        puzzle.conflict = if puzzle.filtered_words.is_empty() {
+            constraints.conflict(puzzle.position_content.len())
+        } else {
+            None
+        };
+
+        puzzle.applied_constraints = Some(constraints);
+        puzzle.applied_only_show_common = self.only_show_common;
+        puzzle.applied_only_plausible = self.only_plausible;
+        puzzle.applied_hide_maybe_words = self.hide_maybe_words;
+        puzzle.applied_regex = regex_text;
+
+        // The list itself may have changed on a full rebuild
+        if has_changed || !is_narrowing {
+            self.update_rejected_words(puzzle_index);
+        }
+
+        let puzzle = &mut self.puzzles[puzzle_index];
+        // Most updates don't change the candidates, only recompute the suggestion when they do
+        if has_changed {
+            // Flash how discriminating a narrowing was
+            puzzle.removed_flash = (puzzle.filtered_words.len() < previous_count)
+                .then(|| (previous_count - puzzle.filtered_words.len(), Instant::now()));
+            puzzle.page = 0;
+            self.update_scores(puzzle_index);
+            self.update_suggested_guess(puzzle_index);
+        }
+
+        has_changed
+    }
+
+    /// Lists the words of a board rejected by its constraints while they are shown, in the order
+    /// of the list
+    fn update_rejected_words(&mut self, puzzle_index: usize) {
+        let puzzle = &mut self.puzzles[puzzle_index];
+        let rejected_words = if self.show_rejected {
+            let matching_words: HashSet<&String> = puzzle.filtered_words.iter().collect();
+            self.words
+                .iter()
+                .filter(|word| !matching_words.contains(word))
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        puzzle.rejected_words = rejected_words;
+    }
+
+    /// Scores the filtered words of a board by how many of them share their letters at the same
+    /// positions, as the frequency suggestion does
+    fn update_scores(&mut self, puzzle_index: usize) {
+        let puzzle = &mut self.puzzles[puzzle_index];
+        let frequencies = solver::PositionalFrequencies::new(&puzzle.filtered_words, &self.weights);
+        puzzle.scores = puzzle
+            .filtered_words
+            .iter()
+            .map(|word| frequencies.score(word))
+            .collect();
+
+        // Ties keep the usual order, common words first then alphabetically
+        puzzle.score_order = (0..puzzle.filtered_words.len()).collect();
+        puzzle
+            .score_order
+            .sort_by_key(|index| std::cmp::Reverse(puzzle.scores[*index]));
+
+        puzzle.vowel_order = puzzle.score_order.clone();
+        puzzle.vowel_order.sort_by_key(|index| {
+            std::cmp::Reverse(solver::distinct_vowel_count(&puzzle.filtered_words[*index]))
+        });
+    }
+
+    fn update_suggested_guess(&mut self, puzzle_index: usize) {
+        let puzzle = &mut self.puzzles[puzzle_index];
+        // In hard mode, guesses must be consistent with the constraints like the candidates
+        let guesses = if self.only_consistent_guesses {
+            &puzzle.filtered_words
+        } else {
+            &self.words
+        };
+
+        let method = if !self.use_entropy_suggestion {
+            SuggestionMethod::LetterFrequency
+        } else if guesses.len() * puzzle.filtered_words.len() <= ENTROPY_PAIR_LIMIT {
+            SuggestionMethod::Entropy
+        } else {
+            SuggestionMethod::EntropyFallback
+        };
+        let suggested_guess = if method == SuggestionMethod::Entropy {
+            solver::best_entropy_guess(guesses, &puzzle.filtered_words, &self.weights)
+        } else {
+            solver::suggest_guess(guesses, &puzzle.filtered_words, &self.weights).cloned()
+        };
+        puzzle.suggested_guess = suggested_guess.map(|word| (word, method));
+    }
+}
+
+impl PuzzleState {
+    fn new(word_length: usize) -> Self {
+        Self {
+            filtered_words: Vec::new(),
+            previous_filtered_words: Vec::new(),
+            rejected_words: Vec::new(),
+            applied_constraints: None,
+            applied_only_show_common: false,
+            applied_only_plausible: false,
+            applied_hide_maybe_words: false,
+            suggested_guess: None,
+            conflict: None,
+            scores: Vec::new(),
+            score_order: Vec::new(),
+            vowel_order: Vec::new(),
+            position_content: (0..word_length).map(|_| Content::new()).collect(),
+            position_exclude_content: (0..word_length).map(|_| Content::new()).collect(),
+            including_content: Content::new(),
+            excluding_content: Content::new(),
+            search_content: Content::new(),
+            regex_content: Content::new(),
+            applied_regex: String::new(),
+            invalid_regex: false,
+            include_mode: IncludeMode::default(),
+            guessed: HashSet::new(),
+            guess_count: 0,
+            played_guesses: Vec::new(),
+            tried_letters: HashSet::new(),
+            pinned: Vec::new(),
+            word_info: None,
+            selected_editor: None,
+            guess_line: String::new(),
+            guess_line_error: None,
+            share_code: String::new(),
+            share_code_error: None,
+            tile_guess: String::new(),
+            tiles: Vec::new(),
+            page: 0,
+            history: Vec::new(),
+            results_offset: 0.0,
+            filter_edited_at: None,
+            removed_flash: None,
+        }
+    }
+
+    /// The words listed in the results, the matching ones unless the rejected ones are shown
+    fn shown_words(&self, show_rejected: bool) -> &[String] {
+        if show_rejected {
+            &self.rejected_words
+        } else {
+            &self.filtered_words
+        }
+    }
+
+    fn page_count(&self, show_rejected: bool) -> usize {
+        self.shown_words(show_rejected)
+            .len()
+            .div_ceil(PAGE_SIZE)
+            .max(1)
+    }
+
+    /// Whether the cursor of the excluding box is in a token starting with a position, like `2E`,
+    /// whose letters aren't deduplicated with the letters excluded from the whole word
+    fn is_typing_excluded_position(&self) -> bool {
+        let cursor = self.excluding_content.cursor().position;
+        let text_before_cursor: String = self
+            .excluding_content
+            .line(cursor.line)
+            .map(|line| line.text.chars().take(cursor.column).collect())
+            .unwrap_or_default();
+        text_before_cursor
+            .split_whitespace()
+            .next_back()
+            .filter(|_| !text_before_cursor.ends_with(' '))
+            .is_some_and(|token| token.starts_with(|character: char| character.is_ascii_digit()))
+    }
+
+    /// Types a letter or a wildcard in the including box, ignoring other characters than a `!`
+    /// after a letter and letters beyond the word length
+    fn insert_including_letter(&mut self, character: char) {
+        let including = self.including_content.text();
+        // A `!` after a letter makes its count exact
+        if character == '!' && including.ends_with(char::is_alphabetic) {
+            self.including_content
+                .perform(Action::Edit(text_editor::Edit::Insert(character)));
+        }
+
+        // Wildcards take the place of a letter
+        let is_wildcard = |c: char| c == constraints::WILDCARD || c == '?';
+        let letter_count = including
+            .chars()
+            .filter(|c| c.is_alphabetic() || is_wildcard(*c))
+            .count();
+        if (character.is_alphabetic() || is_wildcard(character))
+            && letter_count < self.position_content.len()
+        {
+            self.including_content
+                .perform(Action::Edit(text_editor::Edit::Insert(
+                    character.to_ascii_uppercase(),
+                )));
+        }
+    }
+
+    /// Empties the editors and the guess line, keeping what was guessed in the puzzle so far
+    fn clear_filters(&mut self) {
+        self.position_content
+            .iter_mut()
+            .for_each(|content| *content = Content::new());
+        self.position_exclude_content
+            .iter_mut()
+            .for_each(|content| *content = Content::new());
+        self.excluding_content = Content::new();
+        self.including_content = Content::new();
+        self.search_content = Content::new();
+        self.regex_content = Content::new();
+        self.include_mode = IncludeMode::default();
+        self.guess_line.clear();
+        self.guess_line_error = None;
+        self.tile_guess.clear();
+        self.tiles.clear();
+    }
+
+    fn snapshot(&self, label: String) -> FilterSnapshot {
+        FilterSnapshot {
+            label,
+            positions: self
+                .position_content
+                .iter()
+                .map(|content| content.text())
+                .collect(),
+            position_excludes: self
+                .position_exclude_content
+                .iter()
+                .map(|content| content.text())
+                .collect(),
+            including: self.including_content.text(),
+            excluding: self.excluding_content.text(),
+            search: self.search_content.text(),
+            include_mode: self.include_mode,
+            regex: self.regex_content.text(),
+            match_count: self.filtered_words.len(),
+        }
+    }
+
+    /// Restores the editors from a snapshot, ignoring positions saved for another word length
+    fn restore_snapshot(&mut self, snapshot: &FilterSnapshot) {
+        if snapshot.positions.len() == self.position_content.len() {
+            self.position_content = snapshot
+                .positions
+                .iter()
+                .map(|text| Content::with_text(&constraints::normalize_letter_set(text)))
+                .collect();
+        }
+        if snapshot.position_excludes.len() == self.position_exclude_content.len() {
+            self.position_exclude_content = snapshot
+                .position_excludes
+                .iter()
+                .map(|text| Content::with_text(text))
+                .collect();
+        }
+        self.including_content = Content::with_text(&snapshot.including);
+        self.excluding_content = Content::with_text(&snapshot.excluding);
+        self.search_content = Content::with_text(&snapshot.search);
+        self.include_mode = snapshot.include_mode;
+        self.regex_content = Content::with_text(&snapshot.regex);
+    }
+
+    /// The editors as a code to share, along with whether only common words are shown
+    fn share_code(&self, only_show_common: bool) -> ShareCode {
+        ShareCode {
+            positions: self
+                .position_content
+                .iter()
+                .map(|content| content.text())
+                .collect(),
+            position_excludes: self
+                .position_exclude_content
+                .iter()
+                .map(|content| content.text())
+                .collect(),
+            including: self.including_content.text(),
+            // Lines of the excluding box would break the code into several words
+            excluding: self
+                .excluding_content
+                .text()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            only_show_common,
+            include_mode: self.include_mode,
+        }
+    }
+
+    /// Fills the editors from a share code, unless it was made for another word length, and tells
+    /// whether it only shows common words
+    fn import_share_code(&mut self, share_code: &ShareCode) -> Result<bool, String> {
+        let word_length = self.position_content.len();
+        if share_code.positions.len() != word_length
+            || share_code.position_excludes.len() != word_length
+        {
+            return Err(format!(
+                "The share code must have {word_length} boxes per row, like the puzzle"
+            ));
+        }
+
+        self.position_content = share_code
+            .positions
+            .iter()
+            .map(|text| Content::with_text(&constraints::normalize_letter_set(text)))
+            .collect();
+        self.position_exclude_content = share_code
+            .position_excludes
+            .iter()
+            .map(|text| Content::with_text(&constraints::normalize_letter_set(text)))
+            .collect();
+        self.including_content = Content::with_text(&share_code.including);
+        self.excluding_content = Content::with_text(&share_code.excluding);
+        self.include_mode = share_code.include_mode;
+        Ok(share_code.only_show_common)
+    }
+
+    /// Applies a guess and its feedback to the editors, counting it as played, unless it doesn't
+    /// fit the word length or hard mode
+    fn play_guess(&mut self, guess: &[(char, Feedback)], hard_mode: bool) -> Result<(), String> {
+        if guess.len() != self.position_content.len() {
+            return Err(format!(
+                "The guess must have {} letters",
+                self.position_content.len()
+            ));
+        }
+        if hard_mode && !self.is_consistent_guess(guess) {
+            return Err("Hard mode: the guess must reuse all revealed clues".to_string());
+        }
+
+        self.guessed.insert(
+            guess
+                .iter()
+                .map(|(character, _)| character.to_ascii_lowercase())
+                .collect(),
+        );
+        self.guess_count += 1;
+        self.played_guesses.push(guess.to_vec());
+        self.tried_letters.extend(
+            guess
+                .iter()
+                .map(|(character, _)| character.to_ascii_lowercase()),
+        );
+        self.apply_guess(guess);
+        Ok(())
+    }
+
+    /// Whether the guessed word satisfies the current constraints, as required by hard mode
+    fn is_consistent_guess(&self, guess: &[(char, Feedback)]) -> bool {
+        let word: String = guess
+            .iter()
+            .map(|(character, _)| character.to_ascii_lowercase())
+            .collect();
+        self.applied_constraints.as_ref().is_none_or(|constraints| {
+            // The search and the guessed words aren't clues
+            Constraints {
+                search: String::new(),
+                excluded_words: HashSet::new(),
+                ..constraints.clone()
+            }
+            .matches(&word)
+        })
+    }
+
+    /// Folds the feedback of a guess into the editors
+    ///
+    /// Greens fill their position box, yellows are forbidden at their position and added to the
+    /// including box as many times as the guess proves the letter occurs, and grays are excluded,
+    /// which only caps the letter count when another occurrence of it was green or yellow.
+    fn apply_guess(&mut self, guess: &[(char, Feedback)]) {
+        for (index, (character, feedback)) in guess.iter().enumerate() {
+            match feedback {
+                Feedback::Green => {
+                    self.position_content[index] = Content::with_text(&character.to_string());
+                }
+                Feedback::Yellow => {
+                    let mut letters = self.position_exclude_content[index].text();
+                    if !letters.contains(*character) {
+                        letters.push(*character);
+                        self.position_exclude_content[index] = Content::with_text(&letters);
+                    }
+                }
+                Feedback::Gray => {
+                    let excluding = self.excluding_content.text();
+                    let (letters, _) = constraints::parse_excluding(&excluding);
+                    if !letters.contains(*character) {
+                        self.excluding_content = Content::with_text(
+                            &constraints::normalize_excluding(&format!("{excluding} {character}")),
+                        );
+                    }
+                }
+            }
+        }
+
+        // Require yellow letters as many times as they were found in the guess
+        let mut including = self.including_content.text();
+        for (character, _) in guess
+            .iter()
+            .filter(|(_, feedback)| *feedback == Feedback::Yellow)
+        {
+            let found_count = guess
+                .iter()
+                .filter(|(other_character, feedback)| {
+                    other_character == character && *feedback != Feedback::Gray
+                })
+                .count();
+            while including.chars().filter(|c| c == character).count() < found_count {
+                including.push(*character);
+            }
+        }
+        self.including_content = Content::with_text(&including);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced::{
+        Point, Size,
+        widget::text_editor::{Action, Content, Edit, Motion},
+    };
+
+    use std::{collections::HashMap, sync::Arc, time::Instant};
+
+    use wordle_finder::Constraints;
+
+    use super::{App, FILTER_DEBOUNCE, IncludeMode, Message, PositionFocus, SuggestionMethod};
+
+    fn type_letter(content: &mut Content, character: char) -> PositionFocus {
+        App::perform_position_action(content, Action::Edit(Edit::Insert(character)))
+    }
+
+    #[test]
+    fn moves_and_selections_keep_distinct_uppercase_letters() {
+        let mut content = Content::new();
+        assert_eq!(type_letter(&mut content, 's'), PositionFocus::Next);
+        assert_eq!(type_letter(&mut content, 't'), PositionFocus::Stay);
+
+        for action in [
+            Action::Move(Motion::Left),
+            Action::Select(Motion::Home),
+            Action::SelectAll,
+            Action::Move(Motion::DocumentEnd),
+        ] {
+            assert_eq!(
+                App::perform_position_action(&mut content, action),
+                PositionFocus::Stay
+            );
+            assert_eq!(content.text(), "ST");
+        }
+
+        // Typing a letter already in the box, or anything else, leaves it unchanged
+        type_letter(&mut content, 's');
+        type_letter(&mut content, '1');
+        App::perform_position_action(&mut content, Action::Edit(Edit::Enter));
+        assert_eq!(content.text(), "ST");
+    }
+
+    #[test]
+    fn typing_over_a_selection_keeps_distinct_uppercase_letters() {
+        let mut content = Content::with_text("ST");
+        App::perform_position_action(&mut content, Action::SelectAll);
+        type_letter(&mut content, 'a');
+
+        let letters = content.text();
+        assert!(
+            letters
+                .chars()
+                .all(|character| character.is_ascii_uppercase())
+        );
+        assert_eq!(
+            wordle_finder::constraints::normalize_letter_set(&letters),
+            letters
+        );
+    }
+
+    #[test]
+    fn pasted_lowercase_letters_filter_like_typed_ones() {
+        let mut content = Content::new();
+        assert_eq!(
+            App::perform_position_action(
+                &mut content,
+                Action::Edit(Edit::Paste(Arc::new("e".to_string())))
+            ),
+            PositionFocus::Next
+        );
+        assert_eq!(content.text(), "E");
+
+        let greens = App::position_letters(&content)
+            .into_iter()
+            .map(|character| (0, character))
+            .collect();
+        let constraints = Constraints::new(greens, Vec::new(), "", "");
+        assert!(constraints.matches("eerie"));
+        assert!(!constraints.matches("crane"));
+    }
+
+    #[test]
+    fn backspace_on_an_empty_box_goes_back() {
+        let mut content = Content::new();
+        assert_eq!(
+            App::perform_position_action(&mut content, Action::Edit(Edit::Backspace)),
+            PositionFocus::Previous
+        );
+    }
+
+    fn word_list(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    /// An app filtering a few words, about and crane being the common ones
+    fn test_app() -> App {
+        test_app_with(
+            &["about", "arise", "crane", "slate", "trace"],
+            &["crane", "about"],
+        )
+    }
+
+    fn test_app_with(words: &[&str], common_words: &[&str]) -> App {
+        let mut app = App::with_words(word_list(words), word_list(common_words), HashMap::new(), 5);
+        app.update_filtered_words(0);
+        app
+    }
+
+    /// Sends the edits to the app and lets the debounce elapse so that they're filtered
+    fn type_edits(app: &mut App, messages: impl IntoIterator<Item = Message>) {
+        for message in messages {
+            let _ = app.update(message);
+        }
+        let _ = app.update(Message::RecomputeFilters(Instant::now() + FILTER_DEBOUNCE));
+    }
+
+    fn insert(character: char) -> Action {
+        Action::Edit(Edit::Insert(character))
+    }
+
+    #[test]
+    fn gray_duplicate_letter_only_caps_its_count() {
+        // ALLOY against SLOTH: the first L is green, the second one gray and the O yellow, so the
+        // answer has exactly one L
+        let mut app = test_app_with(&["sloth", "ollie"], &[]);
+        type_edits(
+            &mut app,
+            [
+                Message::PositionEditAction(1, insert('l')),
+                Message::PositionExcludeAction(3, insert('o')),
+                Message::ExcludingEditAction(insert('a')),
+                Message::ExcludingEditAction(insert('l')),
+                Message::ExcludingEditAction(insert('y')),
+            ],
+        );
+
+        assert_eq!(app.puzzles[0].filtered_words, word_list(&["sloth"]));
+    }
+
+    #[test]
+    fn both_ls_of_alloy_keep_loyal() {
+        // Against LOYAL, every letter of ALLOY is yellow since the answer has two Ls
+        let mut app = test_app_with(&["loyal", "lemon"], &[]);
+        type_edits(
+            &mut app,
+            "ALLOY"
+                .chars()
+                .enumerate()
+                .map(|(index, letter)| Message::PositionExcludeAction(index, insert(letter))),
+        );
+
+        assert_eq!(app.puzzles[0].filtered_words, word_list(&["loyal"]));
+    }
+
+    #[test]
+    fn common_words_are_listed_first() {
+        let app = test_app();
+
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["about", "crane", "arise", "slate", "trace"])
+        );
+    }
+
+    #[test]
+    fn edits_wait_for_the_typing_to_pause() {
+        let mut app = test_app();
+
+        let _ = app.update(Message::PositionEditAction(0, insert('a')));
+        assert_eq!(app.puzzles[0].filtered_words.len(), 5);
+
+        let _ = app.update(Message::RecomputeFilters(Instant::now()));
+        assert_eq!(app.puzzles[0].filtered_words.len(), 5);
+
+        let _ = app.update(Message::RecomputeFilters(Instant::now() + FILTER_DEBOUNCE));
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["about", "arise"])
+        );
+    }
+
+    #[test]
+    fn yellow_and_gray_letters_narrow_the_results() {
+        let mut app = test_app();
+
+        type_edits(&mut app, [Message::ExcludingEditAction(insert('r'))]);
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["about", "slate"])
+        );
+
+        type_edits(
+            &mut app,
+            [
+                Message::ExcludingEditAction(Action::Edit(Edit::Backspace)),
+                Message::PositionExcludeAction(0, insert('c')),
+            ],
+        );
+        assert_eq!(app.puzzles[0].filtered_words, word_list(&["trace"]));
+    }
+
+    #[test]
+    fn clearing_the_filters_lists_every_word_again() {
+        let mut app = test_app();
+        type_edits(
+            &mut app,
+            [
+                Message::PositionEditAction(1, insert('r')),
+                Message::PositionEditAction(2, insert('a')),
+            ],
+        );
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["crane", "trace"])
+        );
+
+        let _ = app.update(Message::ClearFilters);
+        assert_eq!(app.puzzles[0].filtered_words.len(), 5);
+    }
+
+    #[test]
+    fn removing_the_letters_around_a_wildcard_lists_every_word_again() {
+        let mut app = test_app_with(&["about", "crane", "limbo"], &[]);
+        type_edits(
+            &mut app,
+            [
+                Message::IncludeModeSelected(IncludeMode::AnyOf),
+                Message::IncludingEditAction(insert('a')),
+                Message::IncludingEditAction(insert('.')),
+            ],
+        );
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["about", "crane"])
+        );
+
+        type_edits(
+            &mut app,
+            [
+                Message::IncludingEditAction(Action::Move(Motion::DocumentStart)),
+                Message::IncludingEditAction(Action::Edit(Edit::Delete)),
+            ],
+        );
+        assert_eq!(app.puzzles[0].including_content.text(), ".");
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["about", "crane", "limbo"])
+        );
+    }
+
+    #[test]
+    fn pasted_excluded_letters_are_not_duplicated_by_typing() {
+        let mut app = test_app();
+        type_edits(
+            &mut app,
+            [
+                Message::ExcludingEditAction(Action::Edit(Edit::Paste(Arc::new(
+                    "rst".to_string(),
+                )))),
+                Message::ExcludingEditAction(insert('r')),
+            ],
+        );
+
+        assert_eq!(app.puzzles[0].excluding_content.text(), "RST");
+    }
+
+    #[test]
+    fn several_letters_in_a_position_box_allow_any_of_them() {
+        let mut app = test_app();
+        type_edits(
+            &mut app,
+            [
+                Message::PositionEditAction(0, insert('c')),
+                Message::PositionEditAction(0, insert('s')),
+            ],
+        );
+
+        assert_eq!(app.puzzles[0].position_content[0].text(), "CS");
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["crane", "slate"])
+        );
+    }
+
+    #[test]
+    fn suggestions_tell_which_method_picked_them() {
+        let mut app = test_app();
+        let method = |app: &App| {
+            app.puzzles[0]
+                .suggested_guess
+                .as_ref()
+                .map(|(_, method)| *method)
+        };
+        assert_eq!(method(&app), Some(SuggestionMethod::LetterFrequency));
+
+        let _ = app.update(Message::ToggleEntropySuggestion(true));
+        assert_eq!(method(&app), Some(SuggestionMethod::Entropy));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_brings_back_the_include_mode_and_regex() {
+        let mut app = test_app();
+        type_edits(
+            &mut app,
+            [
+                Message::IncludeModeSelected(IncludeMode::AnyOf),
+                Message::IncludingEditAction(insert('o')),
+                Message::IncludingEditAction(insert('i')),
+                Message::RegexEditAction(insert('a')),
+                Message::RegexEditAction(insert('.')),
+                Message::RegexEditAction(insert('*')),
+            ],
+        );
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["about", "arise"])
+        );
+
+        let _ = app.update(Message::ClearFilters);
+        let _ = app.update(Message::RestoreSnapshot(app.puzzles[0].history.len() - 1));
+        assert_eq!(app.puzzles[0].include_mode, IncludeMode::AnyOf);
+        assert_eq!(app.puzzles[0].regex_content.text(), "a.*");
+        assert_eq!(
+            app.puzzles[0].filtered_words,
+            word_list(&["about", "arise"])
+        );
+    }
+
+    #[test]
+    fn share_codes_carry_the_include_mode_and_stay_on_one_line() {
+        let mut app = test_app();
+        let _ = app.update(Message::IncludeModeSelected(IncludeMode::Anagram));
+        app.puzzles[0].excluding_content = Content::with_text("rs\n2t ");
+
+        let code = app.puzzles[0].share_code(false).to_string();
+        assert_eq!(code, "..../....//rs_2t/0/1");
+
+        let _ = app.update(Message::ClearFilters);
+        let _ = app.update(Message::ShareCodeEdited(code));
+        let _ = app.update(Message::ImportShareCode);
+        assert_eq!(app.puzzles[0].include_mode, IncludeMode::Anagram);
+        assert_eq!(app.puzzles[0].excluding_content.text(), "RS 2T");
+    }
+
+    #[test]
+    fn resizing_the_window_leaves_pending_edits_waiting() {
+        let mut app = test_app();
+        let _ = app.update(Message::PositionEditAction(0, insert('a')));
+        let _ = app.update(Message::WindowResized(Size::new(800.0, 600.0)));
+
+        assert!(app.puzzles[0].filter_edited_at.is_some());
+        assert_eq!(app.puzzles[0].filtered_words.len(), 5);
+    }
+
+    #[test]
+    fn minimizing_the_window_keeps_its_last_geometry() {
+        let mut app = test_app();
+        let _ = app.update(Message::WindowResized(Size::new(800.0, 600.0)));
+        let _ = app.update(Message::WindowMoved(Point::new(40.0, 30.0)));
+
+        // What Windows reports for a minimized window
+        let _ = app.update(Message::WindowResized(Size::new(0.0, 0.0)));
+        let _ = app.update(Message::WindowMoved(Point::new(-32000.0, -32000.0)));
+
+        let state = app.persisted_state();
+        assert_eq!(state.window_size, Some((800.0, 600.0)));
+        assert_eq!(state.window_position, Some((40.0, 30.0)));
     }
 }
@@ -0,0 +1,113 @@
+use std::env;
+
+use wordle_finder::words::{self, NamedWordList};
+
+/// Command-line options of the application
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    pub all_words_path: Option<String>,
+    pub common_words_path: Option<String>,
+    /// Words valid as guesses but never answers, shown apart from rare words
+    pub maybe_words_path: Option<String>,
+    /// Answers of past puzzles, which can be left out of the results
+    pub past_answers_path: Option<String>,
+    /// Downloaded instead of reading `all_words_path`
+    pub all_words_url: Option<String>,
+    /// Downloaded instead of reading `common_words_path`
+    pub common_words_url: Option<String>,
+    /// Preset read instead of the default lists, given by name
+    pub word_list: Option<&'static NamedWordList>,
+    pub word_length: Option<usize>,
+    /// Initial size of the window, the default one when missing
+    pub window_width: Option<f32>,
+    pub window_height: Option<f32>,
+    /// Print the matching words instead of opening a window
+    pub cli: bool,
+    /// Green letters by position, like `.A..E`
+    pub green: String,
+    pub include: String,
+    pub exclude: String,
+}
+
+impl Args {
+    /// Parses the arguments the process was launched with
+    pub fn parse() -> Result<Self, String> {
+        Self::parse_from(env::args().skip(1))
+    }
+
+    /// Parses `--all-words <path>`, `--common-words <path>`, `--maybe-words <path>`,
+    /// `--past-answers <path>`, `--all-words-url <url>`, `--common-words-url <url>`,
+    /// `--wordlist <name>`, `--length <n>`, `--width <pixels>` and `--height <pixels>`, and the
+    /// `--cli` mode constraints `--green <pattern>`, `--include <letters>` and `--exclude <letters>`,
+    /// leaving absent ones to the defaults
+    pub fn parse_from(arguments: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = Self::default();
+
+        let mut arguments = arguments.into_iter();
+        while let Some(argument) = arguments.next() {
+            let mut value = || {
+                arguments
+                    .next()
+                    .ok_or_else(|| format!("Missing value for argument [{argument}]"))
+            };
+            match argument.as_str() {
+                "--all-words" => args.all_words_path = Some(value()?),
+                "--common-words" => args.common_words_path = Some(value()?),
+                "--maybe-words" => args.maybe_words_path = Some(value()?),
+                "--past-answers" => args.past_answers_path = Some(value()?),
+                "--all-words-url" => args.all_words_url = Some(value()?),
+                "--common-words-url" => args.common_words_url = Some(value()?),
+                "--wordlist" => args.word_list = Some(words::named_word_list(&value()?)?),
+                "--length" => {
+                    let length = value()?;
+                    args.word_length = Some(
+                        length
+                            .parse()
+                            .ok()
+                            .filter(|length| *length > 0)
+                            .ok_or_else(|| format!("Invalid word length [{length}]"))?,
+                    )
+                }
+                "--width" => args.window_width = Self::parse_dimension(&argument, &value()?),
+                "--height" => args.window_height = Self::parse_dimension(&argument, &value()?),
+                "--cli" => args.cli = true,
+                "--green" => args.green = value()?,
+                "--include" => args.include = value()?,
+                "--exclude" => args.exclude = value()?,
+                _ => return Err(format!("Unknown argument [{argument}]")),
+            }
+        }
+
+        if args.all_words_path.is_some() && args.all_words_url.is_some() {
+            return Err("Both [--all-words] and [--all-words-url] were given".to_string());
+        }
+        if args.word_list.is_some()
+            && (args.all_words_path.is_some() || args.all_words_url.is_some())
+        {
+            return Err("Both [--wordlist] and the all words list were given".to_string());
+        }
+        if args.common_words_path.is_some() && args.common_words_url.is_some() {
+            return Err("Both [--common-words] and [--common-words-url] were given".to_string());
+        }
+
+        Ok(args)
+    }
+
+    /// Parses a window dimension, ignoring it with a warning unless it is a positive number of
+    /// pixels, so that a bad value only costs the default size
+    fn parse_dimension(argument: &str, dimension: &str) -> Option<f32> {
+        let pixels = dimension
+            .parse()
+            .ok()
+            .filter(|pixels| is_valid_dimension(*pixels));
+        if pixels.is_none() {
+            eprintln!("Ignoring invalid [{argument}] [{dimension}], using the default size");
+        }
+        pixels
+    }
+}
+
+/// Whether a window dimension is a positive number of pixels
+pub fn is_valid_dimension(pixels: f32) -> bool {
+    pixels.is_finite() && pixels > 0.0
+}
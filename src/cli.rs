@@ -0,0 +1,65 @@
+use wordle_finder::{
+    constraints::{self, Constraints},
+    words,
+};
+
+use crate::args::Args;
+
+/// Prints the words matching the constraints given as arguments, one per line
+pub fn run(args: &Args) -> Result<(), String> {
+    let text = match (args.word_list, &args.all_words_url) {
+        (Some(word_list), _) => word_list.all.load(),
+        (None, Some(url)) => words::fetch_word_file(url),
+        (None, None) => words::read_word_list(
+            args.all_words_path.as_deref(),
+            words::DEFAULT_ALL_WORDS_FILE_PATH,
+            words::EMBEDDED_ALL_WORDS,
+        ),
+    }
+    .map_err(|error| error.to_string())?;
+
+    let is_json = args
+        .all_words_url
+        .as_deref()
+        .or(args.all_words_path.as_deref())
+        .is_some_and(words::is_json_path);
+    let (mut words, word_length) = if is_json {
+        let word_lists = words::parse_json_word_lists(&text, args.word_length)
+            .map_err(|error| error.to_string())?;
+        let word_length = word_lists.length.unwrap_or(words::DEFAULT_WORD_LENGTH);
+        (word_lists.all, word_length)
+    } else {
+        let word_length = args
+            .word_length
+            .or_else(|| words::detect_word_length(&text))
+            .unwrap_or(words::DEFAULT_WORD_LENGTH);
+        let words =
+            words::parse_word_file(&text, word_length).map_err(|error| error.to_string())?;
+        (words, word_length)
+    };
+    words::dedupe_words(&mut words);
+
+    if args.green.chars().count() > word_length {
+        return Err(format!(
+            "The green pattern [{}] is longer than the {word_length} letters of the words",
+            args.green
+        ));
+    }
+
+    // Any character other than a letter is an unknown position
+    let greens = args
+        .green
+        .chars()
+        .enumerate()
+        .filter(|(_, character)| character.is_alphabetic())
+        .collect();
+    let constraints = Constraints::new(greens, Vec::new(), &args.include, &args.exclude);
+
+    let mut matching_words = Vec::new();
+    constraints::extend_matching(&mut matching_words, &words, &constraints);
+    for word in matching_words {
+        println!("{word}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,691 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use rayon::iter::{
+    IntoParallelIterator, IntoParallelRefIterator, ParallelExtend, ParallelIterator,
+};
+
+/// Below this many words, filtering on the current thread takes well under a millisecond and
+/// splitting the work across threads costs more than it saves. The default list (about 14k words)
+/// stays single-threaded, while large custom dictionaries are filtered in parallel.
+const PARALLEL_FILTER_THRESHOLD: usize = 20_000;
+
+/// What the constraints tell about a letter, like the colors of Wordle's keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LetterStatus {
+    /// Nothing is known about the letter
+    Unknown,
+    /// The letter isn't in the word
+    Absent,
+    /// The letter is in the word, at an unknown position
+    Present,
+    /// The letter is at a known position
+    Correct,
+}
+
+/// How the letters of the including box are matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub enum IncludeMode {
+    /// The word contains each letter at least as many times as it was typed
+    #[default]
+    AtLeast,
+    /// The word is an arrangement of exactly the typed letters
+    Anagram,
+    /// The word contains the typed letters next to each other, in the typed order
+    Substring,
+    /// The word contains at least one of the typed letters
+    AnyOf,
+}
+
+impl IncludeMode {
+    pub const ALL: [IncludeMode; 4] = [
+        IncludeMode::AtLeast,
+        IncludeMode::Anagram,
+        IncludeMode::Substring,
+        IncludeMode::AnyOf,
+    ];
+}
+
+impl fmt::Display for IncludeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AtLeast => write!(f, "At least"),
+            Self::Anagram => write!(f, "Anagram"),
+            Self::Substring => write!(f, "Substring"),
+            Self::AnyOf => write!(f, "Any of"),
+        }
+    }
+}
+
+/// Stands for any letter in the including box, which also accepts `?` for it
+pub const WILDCARD: char = '.';
+
+/// The constraints a word must satisfy to be a possible answer
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Constraints {
+    /// Letters known to be at a position
+    pub greens: Vec<(usize, char)>,
+    /// Letters allowed at a position, when it is known to be one of several
+    pub allowed: Vec<(usize, Vec<char>)>,
+    /// Letters known to be in the word, but not at a position
+    pub yellows: Vec<(usize, char)>,
+    /// Letters not at a position, without being known to be in the word
+    pub excluded_positions: Vec<(usize, char)>,
+    /// Lowercase letters of the including box, in the typed order, without the `!` marks, and with
+    /// the wildcards as [`WILDCARD`]
+    pub including: String,
+    /// How `including` is matched
+    pub include_mode: IncludeMode,
+    /// Minimum number of occurrences of letters, from the including box and the other clues
+    pub include_counts: HashMap<char, usize>,
+    /// Maximum number of occurrences of excluded letters
+    pub exclude_counts: HashMap<char, usize>,
+    /// Letters occurring exactly as many times as their minimum in `include_counts`, marked with
+    /// a `!` following them in the including box, like `EE!` for exactly two E
+    pub exact_letters: HashSet<char>,
+    /// Lowercase text the word must contain, regardless of Wordle clues
+    pub search: String,
+    /// Lowercase words that can't be the answer, like the ones already guessed
+    pub excluded_words: HashSet<String>,
+}
+
+impl Constraints {
+    /// Builds the constraints from the green and yellow clues, and the letters typed in the
+    /// including and excluding boxes
+    ///
+    /// Letters are compared in lowercase. An excluded letter caps its count to its minimum
+    /// occurrences rather than zero, so a gray duplicate of a green or yellow letter doesn't
+    /// exclude words containing it once.
+    pub fn new(
+        greens: Vec<(usize, char)>,
+        yellows: Vec<(usize, char)>,
+        including: &str,
+        excluding: &str,
+    ) -> Self {
+        Self::with_include_mode(
+            greens,
+            yellows,
+            including,
+            IncludeMode::default(),
+            excluding,
+        )
+    }
+
+    /// Builds the constraints like [`Constraints::new`], matching the including box with
+    /// `include_mode`
+    ///
+    /// In [`IncludeMode::AnyOf`], none of the typed letters is required on its own, so they don't
+    /// count towards the minimum occurrences of letters, and `!` marks are ignored.
+    ///
+    /// Wildcards, `.` or `?`, stand for some letter: they take a place in
+    /// [`IncludeMode::Substring`] and [`IncludeMode::Anagram`] without requiring any letter.
+    pub fn with_include_mode(
+        greens: Vec<(usize, char)>,
+        yellows: Vec<(usize, char)>,
+        including: &str,
+        include_mode: IncludeMode,
+        excluding: &str,
+    ) -> Self {
+        let greens: Vec<(usize, char)> = greens
+            .into_iter()
+            .map(|(index, character)| (index, character.to_ascii_lowercase()))
+            .collect();
+        let yellows: Vec<(usize, char)> = yellows
+            .into_iter()
+            .map(|(index, character)| (index, character.to_ascii_lowercase()))
+            .collect();
+
+        // A `!` makes the count of the letter before it exact
+        let mut exact_letters = HashSet::new();
+        let mut previous_character = None;
+        for character in including.chars().map(|c| c.to_ascii_lowercase()) {
+            if character == '!' {
+                exact_letters.extend(previous_character);
+            }
+            previous_character = Some(character).filter(|character| character.is_alphabetic());
+        }
+        let including: String = including
+            .chars()
+            .filter(|c| *c != '!')
+            .map(|c| if c == '?' { WILDCARD } else { c })
+            .collect();
+
+        // Count character frequency
+        let mut include_counts: HashMap<char, usize> = HashMap::new();
+        let required_including = if include_mode == IncludeMode::AnyOf {
+            exact_letters.clear();
+            ""
+        } else {
+            including.as_str()
+        };
+        for character in required_including
+            .chars()
+            .filter(|c| *c != WILDCARD)
+            .map(|c| c.to_ascii_lowercase())
+        {
+            include_counts
+                .entry(character)
+                .and_modify(|frequency| *frequency += 1)
+                .or_insert(1);
+        }
+
+        // Reconcile the including box with the other clues: a letter is known to appear at least as
+        // many times as it is required by the including box, by green positions, or once if it is
+        // yellow somewhere. A green letter thus already satisfies one occurrence typed in the
+        // including box instead of requiring another one.
+        let mut green_counts: HashMap<char, usize> = HashMap::new();
+        for (_, character) in &greens {
+            *green_counts.entry(*character).or_insert(0) += 1;
+        }
+        for (character, count) in green_counts {
+            let minimum = include_counts.entry(character).or_insert(0);
+            *minimum = (*minimum).max(count);
+        }
+        for (_, character) in &yellows {
+            let minimum = include_counts.entry(*character).or_insert(0);
+            *minimum = (*minimum).max(1);
+        }
+
+        let exclude_counts = excluding
+            .chars()
+            .map(|character| {
+                let character = character.to_ascii_lowercase();
+                (
+                    character,
+                    include_counts.get(&character).copied().unwrap_or(0),
+                )
+            })
+            .collect();
+
+        Self {
+            greens,
+            allowed: Vec::new(),
+            yellows,
+            excluded_positions: Vec::new(),
+            including: including.to_lowercase(),
+            include_mode,
+            include_counts,
+            exclude_counts,
+            exact_letters,
+            search: String::new(),
+            excluded_words: HashSet::new(),
+        }
+    }
+
+    /// Whether `word` satisfies every constraint
+    pub fn matches(&self, word: &str) -> bool {
+        let count = |character: char| word.chars().filter(|c| *c == character).count();
+
+        // Filter by position
+        self.greens
+            .iter()
+            .all(|(index, character)| word.chars().nth(*index) == Some(*character))
+        // Filter by allowed letters at a position
+        && self.allowed.iter().all(|(index, letters)| {
+            word.chars().nth(*index).is_some_and(|character| letters.contains(&character))
+        })
+        // Filter by letters present but not at this position
+        && self.yellows.iter().all(|(index, character)| {
+            word.contains(*character) && word.chars().nth(*index) != Some(*character)
+        })
+        // Filter by letters excluded from a position
+        && self
+            .excluded_positions
+            .iter()
+            .all(|(index, character)| word.chars().nth(*index) != Some(*character))
+        // Filter by exclude
+        && self
+            .exclude_counts
+            .iter()
+            .all(|(character, maximum)| count(*character) <= *maximum)
+        // Filter by include
+        && self
+            .include_counts
+            .iter()
+            .all(|(character, minimum)| count(*character) >= *minimum)
+        // Filter by exact counts
+        && self.exact_letters.iter().all(|character| {
+            Some(&count(*character)) == self.include_counts.get(character)
+        })
+        // Filter by the arrangement of the including letters
+        && self.matches_arrangement(word)
+        // Filter by search
+        && (self.search.is_empty() || word.to_lowercase().contains(&self.search))
+        // Filter by excluded words
+        && !self.excluded_words.contains(word)
+    }
+
+    /// Whether the letters of the including box are arranged in `word` as the include mode asks
+    fn matches_arrangement(&self, word: &str) -> bool {
+        match self.include_mode {
+            IncludeMode::AtLeast => true,
+            // Wildcards take the letters left over once the typed ones are matched
+            IncludeMode::Anagram => {
+                let mut characters: Vec<char> = word.chars().collect();
+                characters.len() == self.including.chars().count()
+                    && self
+                        .including
+                        .chars()
+                        .filter(|character| *character != WILDCARD)
+                        .all(|character| {
+                            characters
+                                .iter()
+                                .position(|c| *c == character)
+                                .map(|index| characters.swap_remove(index))
+                                .is_some()
+                        })
+            }
+            IncludeMode::Substring => {
+                let characters: Vec<char> = word.chars().collect();
+                let pattern: Vec<char> = self.including.chars().collect();
+                pattern.is_empty()
+                    || characters.windows(pattern.len()).any(|window| {
+                        window.iter().zip(&pattern).all(|(character, expected)| {
+                            *expected == WILDCARD || character == expected
+                        })
+                    })
+            }
+            // Wildcards don't allow any letter on their own
+            IncludeMode::AnyOf => {
+                let mut letters = self
+                    .including
+                    .chars()
+                    .filter(|character| *character != WILDCARD)
+                    .peekable();
+                letters.peek().is_none() || letters.any(|character| word.contains(character))
+            }
+        }
+    }
+
+    /// Tells, for each kind of clue set, whether `word` satisfies it, like `("Yellow letters", true)`
+    ///
+    /// This explains [`Constraints::matches`] one clue at a time, for words to inspect rather than
+    /// on every filtering: `word` matches when every check passes.
+    pub fn clue_checks(&self, word: &str) -> Vec<(&'static str, bool)> {
+        let count = |character: char| word.chars().filter(|c| *c == character).count();
+        let letter_at = |index: usize| word.chars().nth(index);
+
+        let checks = [
+            (
+                "Green letters",
+                !self.greens.is_empty(),
+                self.greens
+                    .iter()
+                    .all(|(index, character)| letter_at(*index) == Some(*character)),
+            ),
+            (
+                "Letters allowed at a position",
+                !self.allowed.is_empty(),
+                self.allowed.iter().all(|(index, letters)| {
+                    letter_at(*index).is_some_and(|character| letters.contains(&character))
+                }),
+            ),
+            (
+                "Yellow letters",
+                !self.yellows.is_empty(),
+                self.yellows.iter().all(|(index, character)| {
+                    word.contains(*character) && letter_at(*index) != Some(*character)
+                }),
+            ),
+            (
+                "Letters excluded from a position",
+                !self.excluded_positions.is_empty(),
+                self.excluded_positions
+                    .iter()
+                    .all(|(index, character)| letter_at(*index) != Some(*character)),
+            ),
+            (
+                "Excluded letters",
+                !self.exclude_counts.is_empty(),
+                self.exclude_counts
+                    .iter()
+                    .all(|(character, maximum)| count(*character) <= *maximum),
+            ),
+            (
+                "Included letters",
+                !self.include_counts.is_empty(),
+                self.include_counts
+                    .iter()
+                    .all(|(character, minimum)| count(*character) >= *minimum)
+                    && self.exact_letters.iter().all(|character| {
+                        Some(&count(*character)) == self.include_counts.get(character)
+                    }),
+            ),
+            (
+                "Arrangement of the included letters",
+                self.include_mode != IncludeMode::AtLeast && !self.including.is_empty(),
+                self.matches_arrangement(word),
+            ),
+            (
+                "Search",
+                !self.search.is_empty(),
+                word.to_lowercase().contains(&self.search),
+            ),
+            (
+                "Not a guessed or hidden word",
+                !self.excluded_words.is_empty(),
+                !self.excluded_words.contains(word),
+            ),
+        ];
+
+        checks
+            .into_iter()
+            .filter(|(_, is_set, _)| *is_set)
+            .map(|(label, _, is_satisfied)| (label, is_satisfied))
+            .collect()
+    }
+
+    /// Describes a contradiction between the clues explaining why no word of `word_length` letters
+    /// matches, if there is one
+    ///
+    /// A required letter contradicts the clues when every position either rules it out or holds
+    /// another letter. A letter both required and excluded only caps its count, which is the usual
+    /// reading of a gray duplicate, so it is only reported after the actual contradictions as the
+    /// likely culprit.
+    pub fn conflict(&self, word_length: usize) -> Option<String> {
+        if let Some((index, character)) = self
+            .greens
+            .iter()
+            .find(|green| self.yellows.contains(green))
+        {
+            return Some(format!(
+                "Conflict: {} is both at and not at position {}",
+                character.to_ascii_uppercase(),
+                index + 1
+            ));
+        }
+
+        let required_count: usize = self.include_counts.values().sum();
+        if required_count > word_length {
+            return Some(format!(
+                "Conflict: {required_count} letters are required in a {word_length} letter word"
+            ));
+        }
+
+        let mut unplaceable_characters: Vec<char> = self
+            .include_counts
+            .keys()
+            .filter(|character| {
+                (0..word_length).all(|index| {
+                    self.yellows.contains(&(index, **character))
+                        || self.excluded_positions.contains(&(index, **character))
+                        || self.greens.iter().any(|(green_index, green)| {
+                            *green_index == index && green != *character
+                        })
+                        || self.allowed.iter().any(|(allowed_index, letters)| {
+                            *allowed_index == index && !letters.contains(character)
+                        })
+                })
+            })
+            .copied()
+            .collect();
+        unplaceable_characters.sort_unstable();
+        if let Some(character) = unplaceable_characters.first() {
+            return Some(format!(
+                "Conflict: {} can't be at any position",
+                character.to_ascii_uppercase()
+            ));
+        }
+
+        let mut excluded_characters: Vec<char> = self
+            .exclude_counts
+            .keys()
+            .filter(|character| self.include_counts.contains_key(character))
+            .copied()
+            .collect();
+        excluded_characters.sort_unstable();
+        excluded_characters.first().map(|character| {
+            format!(
+                "Conflict: {} is both required and excluded",
+                character.to_ascii_uppercase()
+            )
+        })
+    }
+
+    /// What is known about a lowercase letter
+    pub fn letter_status(&self, character: char) -> LetterStatus {
+        if self.greens.iter().any(|(_, green)| *green == character) {
+            LetterStatus::Correct
+        } else if self.include_counts.contains_key(&character)
+            || self.yellows.iter().any(|(_, yellow)| *yellow == character)
+        {
+            LetterStatus::Present
+        } else if self.exclude_counts.get(&character) == Some(&0) {
+            LetterStatus::Absent
+        } else {
+            LetterStatus::Unknown
+        }
+    }
+
+    /// The lowercase letters that can't be at any position of a `word_length` letter word whose
+    /// letter is still unknown, in alphabetical order
+    ///
+    /// A letter is impossible when its maximum count is already met by green letters, which
+    /// includes excluded letters, when it is excluded from every unknown position, or when the
+    /// unknown positions are all taken by required letters. Letters other than `a` to `z` are only
+    /// considered when the constraints mention them.
+    pub fn impossible_letters(&self, word_length: usize) -> Vec<char> {
+        let green_count = |character: char| {
+            self.greens
+                .iter()
+                .filter(|(_, green)| *green == character)
+                .count()
+        };
+        let unknown_positions: Vec<usize> = (0..word_length)
+            .filter(|index| {
+                self.greens
+                    .iter()
+                    .all(|(green_index, _)| green_index != index)
+            })
+            .collect();
+        let unplaced_count: usize = self
+            .include_counts
+            .iter()
+            .map(|(character, minimum)| minimum.saturating_sub(green_count(*character)))
+            .sum();
+
+        let mut letters: Vec<char> = ('a'..='z')
+            .chain(self.include_counts.keys().copied())
+            .chain(self.exclude_counts.keys().copied())
+            .collect();
+        letters.sort_unstable();
+        letters.dedup();
+
+        letters
+            .into_iter()
+            .filter(|character| {
+                let maximum = self
+                    .exact_letters
+                    .contains(character)
+                    .then(|| self.include_counts.get(character).copied())
+                    .flatten()
+                    .or_else(|| self.exclude_counts.get(character).copied());
+                let is_capped = maximum.is_some_and(|maximum| maximum <= green_count(*character));
+
+                let is_excluded_everywhere = unknown_positions.iter().all(|index| {
+                    self.yellows.contains(&(*index, *character))
+                        || self.excluded_positions.contains(&(*index, *character))
+                        || self.allowed.iter().any(|(allowed_index, letters)| {
+                            allowed_index == index && !letters.contains(character)
+                        })
+                });
+
+                let is_crowded_out = unplaced_count >= unknown_positions.len()
+                    && self.include_counts.get(character).copied().unwrap_or(0)
+                        <= green_count(*character);
+
+                is_capped || is_excluded_everywhere || is_crowded_out
+            })
+            .collect()
+    }
+
+    /// Whether every word matching these constraints also matches `other`
+    ///
+    /// This holds when these constraints contain all of `other`'s, possibly tightened, in which
+    /// case words matching `other` can be narrowed down instead of filtering from scratch.
+    pub fn is_stricter_than(&self, other: &Constraints) -> bool {
+        self.include_mode == other.include_mode
+            && match self.include_mode {
+                IncludeMode::AtLeast => true,
+                IncludeMode::Anagram => self.including == other.including,
+                IncludeMode::Substring => self.including.contains(&other.including),
+                // Allowing fewer letters is stricter
+                // Wildcards allow no letter, so only the typed letters are compared
+                IncludeMode::AnyOf => {
+                    let letters = |including: &str| -> Vec<char> {
+                        including
+                            .chars()
+                            .filter(|character| *character != WILDCARD)
+                            .collect()
+                    };
+                    let (own_letters, other_letters) =
+                        (letters(&self.including), letters(&other.including));
+                    other_letters.is_empty()
+                        || (!own_letters.is_empty()
+                            && own_letters
+                                .iter()
+                                .all(|character| other_letters.contains(character)))
+                }
+            }
+            && other.greens.iter().all(|green| self.greens.contains(green))
+            && other.allowed.iter().all(|(index, letters)| {
+                self.greens
+                    .iter()
+                    .any(|(own_index, character)| own_index == index && letters.contains(character))
+                    || self.allowed.iter().any(|(own_index, own_letters)| {
+                        own_index == index
+                            && own_letters
+                                .iter()
+                                .all(|character| letters.contains(character))
+                    })
+            })
+            && other
+                .yellows
+                .iter()
+                .all(|yellow| self.yellows.contains(yellow))
+            && other
+                .excluded_positions
+                .iter()
+                .all(|excluded_position| self.excluded_positions.contains(excluded_position))
+            && other.include_counts.iter().all(|(character, minimum)| {
+                self.include_counts
+                    .get(character)
+                    .is_some_and(|own_minimum| own_minimum >= minimum)
+            })
+            && other.exclude_counts.iter().all(|(character, maximum)| {
+                self.exclude_counts
+                    .get(character)
+                    .is_some_and(|own_maximum| own_maximum <= maximum)
+            })
+            && other.exact_letters.iter().all(|character| {
+                self.exact_letters.contains(character)
+                    && self.include_counts.get(character) == other.include_counts.get(character)
+            })
+            && self.search.contains(&other.search)
+            && self.excluded_words.is_superset(&other.excluded_words)
+    }
+}
+
+/// Normalizes the text of a box holding a set of letters, like the excluding box, to its distinct
+/// letters in uppercase, in the typed order
+///
+/// Other characters are dropped, and letters are deduplicated regardless of their case.
+pub fn normalize_letter_set(text: &str) -> String {
+    let mut letters = String::new();
+    for character in text.chars().filter(|character| character.is_alphabetic()) {
+        let character = character.to_ascii_uppercase();
+        if !letters.contains(character) {
+            letters.push(character);
+        }
+    }
+    letters
+}
+
+/// Splits the text of the excluding box into the letters excluded from the whole word and the
+/// letters excluded from a position
+///
+/// Space separated tokens starting with a position, like `2E` for "no E at position 2", exclude
+/// their letters from that position, counted from 1. The letters of other tokens are excluded from
+/// the whole word. Letters are returned in uppercase.
+pub fn parse_excluding(text: &str) -> (String, Vec<(usize, char)>) {
+    let mut letters = String::new();
+    let mut positions = Vec::new();
+    for token in text.split_whitespace() {
+        let digit_count = token
+            .chars()
+            .take_while(|character| character.is_ascii_digit())
+            .count();
+        if digit_count == 0 {
+            letters.push_str(token);
+            continue;
+        }
+
+        let (position, position_letters) = token.split_at(digit_count);
+        if let Some(index) = position
+            .parse::<usize>()
+            .ok()
+            .and_then(|position| position.checked_sub(1))
+        {
+            for character in position_letters
+                .chars()
+                .filter(|character| character.is_alphabetic())
+            {
+                let character = character.to_ascii_uppercase();
+                if !positions.contains(&(index, character)) {
+                    positions.push((index, character));
+                }
+            }
+        }
+    }
+    (normalize_letter_set(&letters), positions)
+}
+
+/// Normalizes the text of the excluding box, putting the distinct letters excluded from the whole
+/// word in uppercase first, followed by the positional tokens like `2E`
+pub fn normalize_excluding(text: &str) -> String {
+    let (letters, positions) = parse_excluding(text);
+    let mut tokens: Vec<String> = Vec::new();
+    if !letters.is_empty() {
+        tokens.push(letters);
+    }
+    tokens.extend(
+        positions
+            .into_iter()
+            .map(|(index, character)| format!("{}{character}", index + 1)),
+    );
+    tokens.join(" ")
+}
+
+/// Appends the words of `source` matching `constraints` to `words`, keeping their order
+pub fn extend_matching(words: &mut Vec<String>, source: &[String], constraints: &Constraints) {
+    if source.len() < PARALLEL_FILTER_THRESHOLD {
+        words.extend(
+            source
+                .iter()
+                .filter(|word| constraints.matches(word))
+                .cloned(),
+        );
+    } else {
+        words.par_extend(
+            source
+                .par_iter()
+                .filter(|word| constraints.matches(word))
+                .cloned(),
+        );
+    }
+}
+
+/// Removes the words not matching `constraints`, keeping the order of the others
+pub fn retain_matching(words: &mut Vec<String>, constraints: &Constraints) {
+    if words.len() < PARALLEL_FILTER_THRESHOLD {
+        words.retain(|word| constraints.matches(word));
+    } else {
+        *words = std::mem::take(words)
+            .into_par_iter()
+            .filter(|word| constraints.matches(word))
+            .collect();
+    }
+}
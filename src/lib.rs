@@ -0,0 +1,17 @@
+//! Wordle word matching, independent of the GUI
+//!
+//! [`Constraints`] describes what is known about the answer, and [`matches`] tells whether a word
+//! satisfies it. [`words`] loads word lists, [`solver`] suggests guesses and [`share`] encodes what
+//! is known as a code to send.
+
+pub mod constraints;
+pub mod share;
+pub mod solver;
+pub mod words;
+
+pub use constraints::Constraints;
+
+/// Whether `word` satisfies every constraint of `constraints`
+pub fn matches(word: &str, constraints: &Constraints) -> bool {
+    constraints.matches(word)
+}
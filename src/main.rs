@@ -1,19 +1,77 @@
-use iced::{Font, Size, window::Settings};
+use iced::{
+    Font, Point, Size,
+    window::{Position, Settings},
+};
 
-use crate::app::App;
+use crate::{
+    app::{App, INITIAL_WINDOW_SIZE},
+    args::Args,
+    persistence::PersistedState,
+};
 
 mod app;
+mod args;
+mod cli;
+mod persistence;
 
 fn main() -> iced::Result {
-    iced::application(App::new, App::update, App::view)
-        .window(Settings {
-            size: Size {
-                width: 1080.0,
-                height: 600.0,
-            },
-            resizable: false,
-            ..Settings::default()
-        })
-        .default_font(Font::MONOSPACE)
-        .run()
+    let args = Args::parse().unwrap_or_else(|error| {
+        eprintln!("{error}");
+        std::process::exit(2);
+    });
+
+    if args.cli {
+        if let Err(error) = cli::run(&args) {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Reopen the window where it was left, unless given another size
+    let saved_state = PersistedState::load().unwrap_or_default();
+    let (saved_width, saved_height) = saved_state
+        .window_size
+        .filter(|size| persistence::is_valid_window_size(*size))
+        .unwrap_or((INITIAL_WINDOW_SIZE.width, INITIAL_WINDOW_SIZE.height));
+    let window_size = Size {
+        width: args.window_width.unwrap_or(saved_width),
+        height: args.window_height.unwrap_or(saved_height),
+    };
+    let window_position = saved_state
+        .window_position
+        .filter(|position| persistence::is_valid_window_position(*position))
+        .map(|(x, y)| Point::new(x, y));
+
+    iced::application(
+        move || {
+            App::new(
+                args.all_words_path.as_deref(),
+                args.common_words_path.as_deref(),
+                args.maybe_words_path.as_deref(),
+                args.past_answers_path.as_deref(),
+                args.all_words_url.as_deref(),
+                args.common_words_url.as_deref(),
+                args.word_list,
+                args.word_length,
+                window_size,
+                window_position,
+            )
+        },
+        App::update,
+        App::view,
+    )
+    .window(Settings {
+        size: window_size,
+        position: window_position.map_or(Position::default(), Position::Specific),
+        resizable: true,
+        // The app saves the window geometry before closing it
+        exit_on_close_request: false,
+        ..Settings::default()
+    })
+    .title(App::title)
+    .subscription(App::subscription)
+    .theme(App::theme)
+    .default_font(Font::MONOSPACE)
+    .run()
 }
@@ -0,0 +1,108 @@
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use wordle_finder::constraints::IncludeMode;
+
+use crate::args;
+
+/// Lowest coordinate of a window position worth keeping, Windows moving minimized windows to
+/// (-32000, -32000)
+const MIN_WINDOW_COORDINATE: f32 = -16_000.0;
+
+/// The contents of the editors at a point of the puzzle, to come back to it later
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilterSnapshot {
+    /// What led to this point, like the applied guess
+    pub label: String,
+    pub positions: Vec<String>,
+    pub position_excludes: Vec<String>,
+    pub including: String,
+    pub excluding: String,
+    pub search: String,
+    pub include_mode: IncludeMode,
+    /// Pattern of the regex box
+    pub regex: String,
+    /// Number of matching words at this point
+    pub match_count: usize,
+}
+
+/// The part of the application state kept between launches
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PersistedState {
+    pub positions: Vec<String>,
+    pub position_excludes: Vec<String>,
+    pub including: String,
+    pub excluding: String,
+    pub only_show_common: bool,
+    /// Number of badges per line of the results, 0 when never saved
+    pub columns: usize,
+    /// Snapshots of the editors, oldest first
+    pub history: Vec<FilterSnapshot>,
+    /// Lowercase words guessed in the current puzzle, sorted
+    pub guessed: Vec<String>,
+    /// Number of guesses applied in the current puzzle
+    pub guess_count: usize,
+    /// Guesses applied in the current puzzle and their feedback, like `CRANE:BYGBG`, in the order
+    /// they were played
+    pub played_guesses: Vec<String>,
+    /// Lowercase words pinned in the current puzzle, in the order they were pinned
+    pub pinned: Vec<String>,
+    /// Whether guessed words are kept in the results, hidden by default
+    pub show_guessed: bool,
+    pub high_contrast: bool,
+    /// Whether the answers of past puzzles are left out of the results
+    pub hide_past_answers: bool,
+    /// Width and height of the window when it was last resized
+    pub window_size: Option<(f32, f32)>,
+    /// Position of the window on the screen when it was last moved
+    pub window_position: Option<(f32, f32)>,
+}
+
+impl PersistedState {
+    fn path() -> Option<PathBuf> {
+        // Tests mustn't read or overwrite the user's state
+        if cfg!(test) {
+            return None;
+        }
+        dirs::config_dir().map(|config_dir| config_dir.join("wordle_finder").join("state.json"))
+    }
+
+    /// Loads the saved state, if there is a readable and well-formed one
+    pub fn load() -> Option<Self> {
+        let text = fs::read_to_string(Self::path()?).ok()?;
+        serde_json::from_str(&text).ok()
+    }
+
+    /// Saves the state, reporting failures on stderr since they shouldn't interrupt the user
+    pub fn save(&self) {
+        let Some(path) = Self::path() else {
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                let text = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+                fs::write(&path, text)
+            });
+        if let Err(error) = result {
+            eprintln!("Failed to save state to [{}]: {error}", path.display());
+        }
+    }
+}
+
+/// Whether a window size is worth saving and restoring, unlike the empty size of a minimized window
+pub fn is_valid_window_size((width, height): (f32, f32)) -> bool {
+    args::is_valid_dimension(width) && args::is_valid_dimension(height)
+}
+
+/// Whether a window position is worth saving and restoring, unlike the far off screen position of a
+/// minimized window
+pub fn is_valid_window_position((x, y): (f32, f32)) -> bool {
+    [x, y]
+        .iter()
+        .all(|coordinate| coordinate.is_finite() && *coordinate > MIN_WINDOW_COORDINATE)
+}
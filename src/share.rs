@@ -0,0 +1,124 @@
+use std::fmt;
+
+use crate::constraints::IncludeMode;
+
+/// What is known about the answer, as a short code to send to someone solving the same puzzle
+///
+/// The code lists the position boxes, the not in position boxes, the including box, the excluding
+/// box, whether only common words are shown and the index of the include mode in
+/// [`IncludeMode::ALL`], separated by `/`, like `A...E/.R.../L/RST_2E/1/0`. Boxes of a row are
+/// separated by `.` and the spaces of the excluding box become `_`, so that the code is a single
+/// word. Codes without the include mode, from before it was shared, match at least the letters.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ShareCode {
+    /// Letters of each position box, uppercase
+    pub positions: Vec<String>,
+    /// Letters of each not in position box, uppercase
+    pub position_excludes: Vec<String>,
+    /// Letters of the including box, their `!` marks and its wildcards
+    pub including: String,
+    /// Letters and excluded positions of the excluding box, like `RST 2E`
+    pub excluding: String,
+    pub only_show_common: bool,
+    /// How the letters of the including box are matched
+    pub include_mode: IncludeMode,
+}
+
+impl ShareCode {
+    /// Parses a code written by the [`Display`](fmt::Display) implementation, uppercasing its
+    /// letters
+    pub fn parse(code: &str) -> Result<Self, String> {
+        let mut sections: Vec<&str> = code.trim().split('/').collect();
+        if sections.len() == 5 {
+            sections.push("0");
+        }
+        let [
+            positions,
+            position_excludes,
+            including,
+            excluding,
+            only_show_common,
+            include_mode,
+        ] = sections.as_slice()
+        else {
+            return Err(format!(
+                "Expected a share code like [A...E/.R.../L/RST_2E/1/0], got [{code}]"
+            ));
+        };
+
+        let only_show_common = match *only_show_common {
+            "0" => false,
+            "1" => true,
+            other => {
+                return Err(format!(
+                    "Invalid common words flag [{other}], expected 0 or 1"
+                ));
+            }
+        };
+
+        let include_mode = include_mode
+            .parse()
+            .ok()
+            .and_then(|index: usize| IncludeMode::ALL.get(index).copied())
+            .ok_or_else(|| {
+                format!(
+                    "Invalid include mode [{include_mode}], expected 0 to {}",
+                    IncludeMode::ALL.len() - 1
+                )
+            })?;
+
+        Ok(Self {
+            positions: parse_boxes(positions)?,
+            position_excludes: parse_boxes(position_excludes)?,
+            including: parse_letters(including, |character| matches!(character, '!' | '.' | '?'))?,
+            excluding: parse_letters(excluding, |character| {
+                character.is_ascii_digit() || character == '_'
+            })?
+            .replace('_', " "),
+            only_show_common,
+            include_mode,
+        })
+    }
+}
+
+impl fmt::Display for ShareCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}/{}/{}/{}",
+            self.positions.join("."),
+            self.position_excludes.join("."),
+            self.including,
+            self.excluding.replace(' ', "_"),
+            u8::from(self.only_show_common),
+            IncludeMode::ALL
+                .iter()
+                .position(|include_mode| *include_mode == self.include_mode)
+                .unwrap_or_default()
+        )
+    }
+}
+
+/// Parses a row of boxes separated by `.`, each holding letters
+fn parse_boxes(section: &str) -> Result<Vec<String>, String> {
+    section
+        .split('.')
+        .map(|letters| parse_letters(letters, |_| false))
+        .collect()
+}
+
+/// Uppercases the letters of a section, which may only hold other characters allowed by `is_mark`
+fn parse_letters(section: &str, is_mark: impl Fn(char) -> bool) -> Result<String, String> {
+    section
+        .chars()
+        .map(|character| {
+            if character.is_alphabetic() {
+                Ok(character.to_ascii_uppercase())
+            } else if is_mark(character) {
+                Ok(character)
+            } else {
+                Err(format!("Invalid character [{character}] in share code"))
+            }
+        })
+        .collect()
+}
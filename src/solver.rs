@@ -0,0 +1,275 @@
+use std::collections::{HashMap, HashSet};
+
+/// Weight of a candidate, how likely it is to be the answer, 1 when it has none
+fn weight(weights: &HashMap<String, u32>, word: &str) -> usize {
+    weights.get(word).map_or(1, |weight| *weight as usize)
+}
+
+/// How many candidates have each letter at each position, each candidate counting as many times as
+/// its weight
+pub struct PositionalFrequencies(HashMap<(usize, char), usize>);
+
+impl PositionalFrequencies {
+    /// Counts the letters of `candidates`, weighted by `weights`, which can be empty to weigh
+    /// them all equally
+    pub fn new(candidates: &[String], weights: &HashMap<String, u32>) -> Self {
+        let mut frequencies: HashMap<(usize, char), usize> = HashMap::new();
+        for word in candidates {
+            let weight = weight(weights, word);
+            for (index, character) in word.chars().enumerate() {
+                *frequencies.entry((index, character)).or_insert(0) += weight;
+            }
+        }
+        Self(frequencies)
+    }
+
+    /// Sums, for each letter of `word`, how many candidates have that letter at that position
+    ///
+    /// Repeated letters only score their first occurrence, since a guess learns little from
+    /// testing the same letter twice.
+    pub fn score(&self, word: &str) -> usize {
+        let mut seen_characters = HashSet::new();
+        word.chars()
+            .enumerate()
+            .filter(|(_, character)| seen_characters.insert(*character))
+            .map(|(index, character)| self.0.get(&(index, character)).copied().unwrap_or(0))
+            .sum()
+    }
+}
+
+/// A letter is rare when fewer than this share of the candidates contain it
+const RARE_LETTER_SHARE: f64 = 0.05;
+
+/// How many candidates contain each letter, counting each candidate once per letter
+pub struct LetterFrequencies {
+    counts: HashMap<char, usize>,
+    candidate_count: usize,
+}
+
+impl LetterFrequencies {
+    pub fn new(candidates: &[String]) -> Self {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for word in candidates {
+            let characters: HashSet<char> = word.chars().collect();
+            for character in characters {
+                *counts.entry(character).or_insert(0) += 1;
+            }
+        }
+        Self {
+            counts,
+            candidate_count: candidates.len(),
+        }
+    }
+
+    /// The share of the candidates containing `character`, between 0 and 1
+    pub fn share(&self, character: char) -> f64 {
+        let count = self.counts.get(&character).copied().unwrap_or(0);
+        count as f64 / self.candidate_count.max(1) as f64
+    }
+}
+
+/// Number of different vowels in `word`, an opening guess testing more of them telling more
+pub fn distinct_vowel_count(word: &str) -> usize {
+    let vowels: HashSet<char> = word
+        .chars()
+        .map(|character| character.to_ascii_lowercase())
+        .filter(|character| matches!(character, 'a' | 'e' | 'i' | 'o' | 'u'))
+        .collect();
+    vowels.len()
+}
+
+/// Whether `word` looks like a word a puzzle would pick as its answer
+///
+/// This is a heuristic fed with the letter frequencies of the candidates: words repeating a letter
+/// three times or more are rejected, and so are words made only of letters that few candidates
+/// contain.
+pub fn is_plausible_answer(word: &str, stats: &LetterFrequencies) -> bool {
+    let mut character_counts: HashMap<char, usize> = HashMap::new();
+    for character in word.chars() {
+        *character_counts.entry(character).or_insert(0) += 1;
+    }
+
+    let has_triple_letter = character_counts.values().any(|count| *count >= 3);
+    let has_only_rare_letters = character_counts
+        .keys()
+        .all(|character| stats.share(*character) < RARE_LETTER_SHARE);
+
+    !has_triple_letter && !has_only_rare_letters
+}
+
+/// Suggests the guess sharing the most letters, at the same positions, with the candidates
+///
+/// Guesses are scored with [`PositionalFrequencies::score`], candidates being weighted by
+/// `weights`. Ties go to the alphabetically first guess, so the suggestion doesn't depend on the
+/// order of the list.
+pub fn suggest_guess<'a>(
+    guesses: &'a [String],
+    candidates: &[String],
+    weights: &HashMap<String, u32>,
+) -> Option<&'a String> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let frequencies = PositionalFrequencies::new(candidates, weights);
+    guesses
+        .iter()
+        .max_by_key(|word| (frequencies.score(word), std::cmp::Reverse(*word)))
+}
+
+/// The color Wordle gives to a guessed letter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feedback {
+    Gray,
+    Yellow,
+    Green,
+}
+
+/// Computes the colors Wordle would give to each letter of `guess` if the answer was `answer`
+///
+/// Greens are assigned first, then yellows from left to right while the answer still has unmatched
+/// occurrences of the letter, so repeated letters are colored like in the game.
+pub fn feedback(guess: &str, answer: &str) -> Vec<Feedback> {
+    let guess: Vec<char> = guess.chars().collect();
+    let answer: Vec<char> = answer.chars().collect();
+
+    let mut feedback = vec![Feedback::Gray; guess.len()];
+    let mut unmatched_characters: HashMap<char, usize> = HashMap::new();
+    for (index, character) in answer.iter().enumerate() {
+        if guess.get(index) == Some(character) {
+            feedback[index] = Feedback::Green;
+        } else {
+            *unmatched_characters.entry(*character).or_insert(0) += 1;
+        }
+    }
+
+    for (index, character) in guess.iter().enumerate() {
+        if feedback[index] == Feedback::Green {
+            continue;
+        }
+        if let Some(count) = unmatched_characters.get_mut(character)
+            && *count > 0
+        {
+            *count -= 1;
+            feedback[index] = Feedback::Yellow;
+        }
+    }
+
+    feedback
+}
+
+/// Picks the guess whose feedback is expected to tell the most about which candidate is the answer
+///
+/// For each guess, the candidates are partitioned by the feedback pattern they would produce and
+/// the guess with the highest entropy (the sum of `-p * log2(p)` over the partitions) wins. This
+/// computes a feedback for every guess and candidate pair, so it is quadratic in the list sizes.
+/// Only the patterns that occur are counted, since there are 3^len possible ones for long words.
+///
+/// Candidates are as likely to be the answer as their weight in `weights`, which can be empty to
+/// weigh them all equally. Ties go to the alphabetically first guess, like in [`suggest_guess`].
+pub fn best_entropy_guess(
+    guesses: &[String],
+    candidates: &[String],
+    weights: &HashMap<String, u32>,
+) -> Option<String> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let total_weight: usize = candidates
+        .iter()
+        .map(|candidate| weight(weights, candidate))
+        .sum();
+
+    guesses
+        .iter()
+        .map(|guess| {
+            let mut partition_sizes: HashMap<Vec<Feedback>, usize> = HashMap::new();
+            for candidate in candidates {
+                *partition_sizes
+                    .entry(feedback(guess, candidate))
+                    .or_insert(0) += weight(weights, candidate);
+            }
+
+            let entropy: f64 = partition_sizes
+                .into_values()
+                .map(|size| {
+                    let probability = size as f64 / total_weight as f64;
+                    -probability * probability.log2()
+                })
+                .sum();
+
+            (guess, entropy)
+        })
+        .max_by(|(guess, entropy), (other_guess, other_entropy)| {
+            entropy
+                .total_cmp(other_entropy)
+                .then_with(|| other_guess.cmp(guess))
+        })
+        .map(|(guess, _)| guess.clone())
+}
+
+/// Parses a guess followed by its feedback, like `CRANE:BYGBG`
+///
+/// Each feedback letter is `B` for a gray letter, `Y` for a yellow one and `G` for a green one.
+pub fn parse_guess_line(line: &str) -> Result<Vec<(char, Feedback)>, String> {
+    let (guess, pattern) = line.trim().split_once(':').ok_or_else(|| {
+        format!("Expected a guess and its feedback like [CRANE:BYGBG], got [{line}]")
+    })?;
+
+    if guess.chars().count() != pattern.chars().count() {
+        return Err(format!(
+            "The guess [{guess}] and its feedback [{pattern}] don't have the same length"
+        ));
+    }
+
+    guess
+        .chars()
+        .zip(pattern.chars())
+        .map(|(character, color)| {
+            if !character.is_alphabetic() {
+                return Err(format!("Invalid letter [{character}] in guess [{guess}]"));
+            }
+            let feedback = match color.to_ascii_uppercase() {
+                'B' => Feedback::Gray,
+                'Y' => Feedback::Yellow,
+                'G' => Feedback::Green,
+                _ => return Err(format!("Invalid feedback [{color}], expected B, Y or G")),
+            };
+            Ok((character.to_ascii_uppercase(), feedback))
+        })
+        .collect()
+}
+
+/// Formats a guess followed by its feedback, like `CRANE:BYGBG`, as [`parse_guess_line`] reads it
+pub fn format_guess_line(guess: &[(char, Feedback)]) -> String {
+    let (word, pattern): (String, String) = guess
+        .iter()
+        .map(|(character, feedback)| {
+            let color = match feedback {
+                Feedback::Gray => 'B',
+                Feedback::Yellow => 'Y',
+                Feedback::Green => 'G',
+            };
+            (character.to_ascii_uppercase(), color)
+        })
+        .unzip();
+    format!("{word}:{pattern}")
+}
+
+/// Renders the feedback of each guess as a row of the emoji grid Wordle shares, like `⬛🟨🟩⬛🟩`
+pub fn emoji_grid(guesses: &[Vec<(char, Feedback)>]) -> String {
+    guesses
+        .iter()
+        .map(|guess| {
+            guess
+                .iter()
+                .map(|(_, feedback)| match feedback {
+                    Feedback::Gray => '⬛',
+                    Feedback::Yellow => '🟨',
+                    Feedback::Green => '🟩',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
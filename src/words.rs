@@ -0,0 +1,479 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env, fmt, fs, io,
+    path::{self, Path},
+};
+
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+pub const DEFAULT_ALL_WORDS_FILE_PATH: &str = "data/all_words.csv";
+pub const DEFAULT_COMMON_WORDS_FILE_PATH: &str = "data/common_words.csv";
+pub const EMBEDDED_ALL_WORDS: &str = include_str!("../data/all_words.csv");
+pub const EMBEDDED_COMMON_WORDS: &str = include_str!("../data/common_words.csv");
+/// Time after which downloading a word list is given up
+#[cfg(feature = "gui")]
+const FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+/// Environment variable naming a directory holding the default word lists, for packaged installs
+pub const DATA_DIR_VARIABLE: &str = "WORDLE_DATA_DIR";
+
+/// Header lines the first line of a word list may be, besides non-alphabetic ones
+const KNOWN_HEADERS: &[&str] = &["word", "words"];
+
+/// Where a list of a [`NamedWordList`] comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordSource {
+    /// The contents of a list embedded in the binary
+    Embedded(&'static str),
+    /// A list downloaded with [`fetch_word_file`]
+    Url(&'static str),
+}
+
+impl WordSource {
+    /// The contents of the list, downloading it if needed
+    #[cfg(feature = "gui")]
+    pub fn load(self) -> Result<String, LoadError> {
+        match self {
+            Self::Embedded(contents) => Ok(contents.to_string()),
+            Self::Url(url) => fetch_word_file(url),
+        }
+    }
+}
+
+/// A preset of word lists, picked by name rather than by path
+#[derive(Debug, PartialEq, Eq)]
+pub struct NamedWordList {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub all: WordSource,
+    /// The common words, none of the words being common when missing
+    pub common: Option<WordSource>,
+}
+
+/// The presets known to [`named_word_list`]
+pub const NAMED_WORD_LISTS: &[NamedWordList] = &[
+    NamedWordList {
+        name: "nyt",
+        description: "The Wordle guesses and common words embedded in the binary",
+        all: WordSource::Embedded(EMBEDDED_ALL_WORDS),
+        common: Some(WordSource::Embedded(EMBEDDED_COMMON_WORDS)),
+    },
+    NamedWordList {
+        name: "sgb",
+        description: "The five letter words of the Stanford GraphBase, downloaded",
+        all: WordSource::Url("https://www-cs-faculty.stanford.edu/~knuth/sgb-words.txt"),
+        common: None,
+    },
+];
+
+/// Finds the preset called `name`, ignoring case, or tells which ones are available
+pub fn named_word_list(name: &str) -> Result<&'static NamedWordList, String> {
+    NAMED_WORD_LISTS
+        .iter()
+        .find(|word_list| word_list.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            let names: Vec<&str> = NAMED_WORD_LISTS
+                .iter()
+                .map(|word_list| word_list.name)
+                .collect();
+            format!(
+                "Unknown word list [{name}], the available ones are {}",
+                names.join(", ")
+            )
+        })
+}
+
+/// Error raised while loading a word list
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file couldn't be read
+    Read { path: String, error: io::Error },
+    /// The file couldn't be downloaded
+    Fetch { url: String, error: String },
+    /// The file isn't a valid word list
+    Parse(ParseError),
+    /// The file isn't a valid JSON word list
+    #[cfg(feature = "json")]
+    Json(String),
+}
+
+/// Number of bad lines listed by a [`ParseError`] message, the others only being counted
+const DISPLAYED_BAD_LINES: usize = 10;
+
+/// Error raised when lines of a word list aren't words of the expected length
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Every offending line, as its number starting at 1 and its word, so that a list can be fixed
+    /// in one pass
+    pub bad_lines: Vec<(usize, String)>,
+    pub expected_length: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid words during word extraction: {} lines weren't exactly {} characters in length",
+            self.bad_lines.len(),
+            self.expected_length
+        )?;
+        for (line, word) in self.bad_lines.iter().take(DISPLAYED_BAD_LINES) {
+            write!(f, "\nAt line [{line}], the word [{word}]")?;
+        }
+        if self.bad_lines.len() > DISPLAYED_BAD_LINES {
+            write!(
+                f,
+                "\nAnd {} more",
+                self.bad_lines.len() - DISPLAYED_BAD_LINES
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ParseError> for LoadError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { path, error } => {
+                let absolute_path = path::absolute(path)
+                    .map(|absolute_path| absolute_path.display().to_string())
+                    .unwrap_or_else(|_| path.clone());
+                write!(f, "Can't read word list at [{absolute_path}]: {error}")
+            }
+            Self::Fetch { url, error } => write!(f, "Can't download word list at [{url}]: {error}"),
+            Self::Parse(error) => error.fmt(f),
+            #[cfg(feature = "json")]
+            Self::Json(error) => write!(f, "Invalid JSON word list: {error}"),
+        }
+    }
+}
+
+/// Reads a whole word list file
+pub fn read_word_file(path: &str) -> Result<String, LoadError> {
+    fs::read_to_string(path).map_err(|error| LoadError::Read {
+        path: path.to_string(),
+        error,
+    })
+}
+
+/// Downloads a whole word list file with a blocking HTTP GET, following redirects
+#[cfg(feature = "gui")]
+pub fn fetch_word_file(url: &str) -> Result<String, LoadError> {
+    let fetch_error = |error: ureq::Error| LoadError::Fetch {
+        url: url.to_string(),
+        error: error.to_string(),
+    };
+
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(FETCH_TIMEOUT))
+        .build()
+        .into();
+    agent
+        .get(url)
+        .call()
+        .map_err(fetch_error)?
+        .body_mut()
+        .read_to_string()
+        .map_err(fetch_error)
+}
+
+/// Reads the word list at `path` if given, otherwise the file at `default_path`, using the
+/// `embedded` copy when that default file doesn't exist
+///
+/// When [`DATA_DIR_VARIABLE`] is set, the default file is looked up in that directory instead,
+/// and must exist there.
+pub fn read_word_list(
+    path: Option<&str>,
+    default_path: &str,
+    embedded: &str,
+) -> Result<String, LoadError> {
+    let data_dir = env::var_os(DATA_DIR_VARIABLE);
+    match (path, data_dir) {
+        (Some(path), _) => read_word_file(path),
+        (None, Some(data_dir)) => {
+            let file_name = Path::new(default_path).file_name().unwrap_or_default();
+            read_word_file(&Path::new(&data_dir).join(file_name).display().to_string())
+        }
+        (None, None) if Path::new(default_path).exists() => read_word_file(default_path),
+        (None, None) => Ok(embedded.to_string()),
+    }
+}
+
+/// Where [`read_word_list`] reads a list from, `embedded` standing for the copy built into the
+/// binary
+pub fn word_list_location(path: Option<&str>, default_path: &str, embedded: &str) -> String {
+    match (path, env::var_os(DATA_DIR_VARIABLE)) {
+        (Some(path), _) => path.to_string(),
+        (None, Some(data_dir)) => {
+            let file_name = Path::new(default_path).file_name().unwrap_or_default();
+            Path::new(&data_dir).join(file_name).display().to_string()
+        }
+        (None, None) if Path::new(default_path).exists() => default_path.to_string(),
+        (None, None) => embedded.to_string(),
+    }
+}
+
+/// Iterates over the numbered lines of a list holding words, skipping blank lines and `#` comments
+///
+/// Lines are trimmed, so that the `\r` of Windows line endings and stray spaces around a word
+/// don't count towards its length.
+fn word_lines(contents: &str) -> impl Iterator<Item = (usize, &str)> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Detects the word length of a list from the length most of its words have, skipping `#`
+/// comments and a header line
+///
+/// On a tie, the length of the earliest word wins.
+pub fn detect_word_length(contents: &str) -> Option<usize> {
+    let mut length_counts: Vec<(usize, usize)> = Vec::new();
+    for (position, (_, line)) in word_lines(contents).enumerate() {
+        if position == 0 && is_header(line, None) {
+            continue;
+        }
+        let length = split_rank(line).0.chars().count();
+        match length_counts.iter_mut().find(|(other, _)| *other == length) {
+            Some((_, count)) => *count += 1,
+            None => length_counts.push((length, 1)),
+        }
+    }
+    // Reversed so that the earliest of the most common lengths is the last maximum
+    length_counts
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(length, _)| length)
+}
+
+/// Whether the first line of a list is a header rather than a word
+///
+/// It is when it isn't alphabetic, like `word,rank`, or when it's a known header like `word`
+/// that isn't `expected_length` letters long, as it could be a word of the list then.
+fn is_header(line: &str, expected_length: Option<usize>) -> bool {
+    let (word, rank) = split_rank(line);
+    let length = word.chars().count();
+    let is_known_header = KNOWN_HEADERS.contains(&word.to_lowercase().as_str())
+        && expected_length.is_none_or(|expected_length| length != expected_length);
+    let has_bad_rank = line.contains(',') && rank.is_none();
+    is_known_header || has_bad_rank || !word.chars().all(|character| character.is_alphabetic())
+}
+
+/// Splits a line into its word and the frequency rank following it, like `about,12`
+fn split_rank(line: &str) -> (&str, Option<u32>) {
+    match line.split_once(',') {
+        Some((word, rank)) => (word.trim_end(), rank.trim().parse().ok()),
+        None => (line, None),
+    }
+}
+
+/// Extracts the words of a list with one word per line, skipping blank lines, `#` comments and a
+/// header line
+///
+/// Each word may be followed by its frequency rank, like `about,12`, which is ignored.
+pub fn parse_word_file(contents: &str, expected_length: usize) -> Result<Vec<String>, ParseError> {
+    parse_ranked_word_file(contents, expected_length).map(|(words, _)| words)
+}
+
+/// Extracts the words of a list with one word per line, along with the frequency ranks following
+/// some of them, like `about,12`
+///
+/// Blank lines and `#` comments are skipped, and so is the first line when it's a header, like
+/// `word,rank`. A lower rank means a more frequent word.
+/// Words without a rank, or with an invalid one, are missing from the ranks. Words of another
+/// length are all reported by the error, not only the first one.
+pub fn parse_ranked_word_file(
+    contents: &str,
+    expected_length: usize,
+) -> Result<(Vec<String>, HashMap<String, u32>), ParseError> {
+    let mut words = Vec::new();
+    let mut ranks = HashMap::new();
+    let mut bad_lines = Vec::new();
+    for (position, (line_number, line)) in word_lines(contents).enumerate() {
+        if position == 0 && is_header(line, Some(expected_length)) {
+            continue;
+        }
+        let (word, rank) = split_rank(line);
+        // Count characters rather than bytes, for lists with accented letters
+        if word.chars().count() != expected_length {
+            bad_lines.push((line_number, word.to_string()));
+            continue;
+        }
+        if let Some(rank) = rank {
+            ranks.insert(word.to_string(), rank);
+        }
+        words.push(word.to_string());
+    }
+
+    if !bad_lines.is_empty() {
+        return Err(ParseError {
+            bad_lines,
+            expected_length,
+        });
+    }
+    Ok((words, ranks))
+}
+
+/// Turns frequency ranks into weights, the most frequent word having the highest weight
+///
+/// Every ranked word weighs more than unranked ones, which have a weight of 1.
+pub fn rank_weights(ranks: &HashMap<String, u32>) -> HashMap<String, u32> {
+    let max_rank = ranks.values().copied().max().unwrap_or(0);
+    ranks
+        .iter()
+        .map(|(word, rank)| (word.clone(), (max_rank - rank).saturating_add(2)))
+        .collect()
+}
+
+/// Keeps the words ranked at or below `max_rank`, in their order, along with the unranked ones
+/// since nothing tells them apart
+pub fn words_up_to_rank(
+    words: &[String],
+    ranks: &HashMap<String, u32>,
+    max_rank: u32,
+) -> Vec<String> {
+    words
+        .iter()
+        .filter(|word| ranks.get(*word).is_none_or(|rank| *rank <= max_rank))
+        .cloned()
+        .collect()
+}
+
+/// Reads and extracts the words of the list at `path`, which may be a JSON word list
+pub fn load_words(path: &str, expected_length: usize) -> Result<Vec<String>, LoadError> {
+    let contents = read_word_file(path)?;
+    #[cfg(feature = "json")]
+    if is_json_path(path) {
+        return Ok(parse_json_word_lists(&contents, Some(expected_length))?.all);
+    }
+    Ok(parse_word_file(&contents, expected_length)?)
+}
+
+/// Both tiers of words of a JSON word list, like
+/// `{"all": ["crane", ...], "common": ["about", ...], "length": 5, "language": "en"}`
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct WordLists {
+    pub all: Vec<String>,
+    pub common: Vec<String>,
+    /// The length of every word, detected from the first word when missing
+    pub length: Option<usize>,
+    pub language: Option<String>,
+}
+
+/// Whether the list at `path` is a JSON word list, from its extension
+#[cfg(feature = "json")]
+pub fn is_json_path(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+}
+
+/// Extracts the words of a JSON word list, checking that they are all of the same length
+///
+/// That length is `expected_length` if given, otherwise the `length` of the file, otherwise the
+/// length of its first word. It is stored in the `length` of the result.
+#[cfg(feature = "json")]
+pub fn parse_json_word_lists(
+    contents: &str,
+    expected_length: Option<usize>,
+) -> Result<WordLists, LoadError> {
+    let mut word_lists: WordLists =
+        serde_json::from_str(contents).map_err(|error| LoadError::Json(error.to_string()))?;
+
+    let length = expected_length
+        .or(word_lists.length)
+        .or_else(|| word_lists.all.first().map(|word| word.chars().count()))
+        .unwrap_or(DEFAULT_WORD_LENGTH);
+    // Report every offending word at once, like the lines of a text list
+    let bad_words: Vec<(&str, &String)> =
+        [("all", &word_lists.all), ("common", &word_lists.common)]
+            .into_iter()
+            .flat_map(|(tier, words)| {
+                words
+                    .iter()
+                    .filter(|word| word.chars().count() != length)
+                    .map(move |word| (tier, word))
+            })
+            .collect();
+    if !bad_words.is_empty() {
+        let mut message = format!(
+            "{} words weren't exactly {length} characters in length",
+            bad_words.len()
+        );
+        for (tier, word) in bad_words.iter().take(DISPLAYED_BAD_LINES) {
+            message.push_str(&format!("\nThe word [{word}] of the [{tier}] list"));
+        }
+        if bad_words.len() > DISPLAYED_BAD_LINES {
+            message.push_str(&format!(
+                "\nAnd {} more",
+                bad_words.len() - DISPLAYED_BAD_LINES
+            ));
+        }
+        return Err(LoadError::Json(message));
+    }
+
+    word_lists.length = Some(length);
+    Ok(word_lists)
+}
+
+/// Appends the common words missing from `words`, so that the common list is always a subset of it
+///
+/// Returns the appended words, in the order of the common list.
+pub fn merge_missing_words(words: &mut Vec<String>, common_words: &[String]) -> Vec<String> {
+    let known_words: HashSet<&String> = words.iter().collect();
+    let missing_words: Vec<String> = common_words
+        .iter()
+        .filter(|word| !known_words.contains(word))
+        .cloned()
+        .collect();
+    words.extend(missing_words.iter().cloned());
+    missing_words
+}
+
+/// Removes the repeated words of a list, keeping the first occurrence of each word in place
+///
+/// Returns how many words were removed.
+pub fn dedupe_words(words: &mut Vec<String>) -> usize {
+    let previous_len = words.len();
+    let mut seen_words = HashSet::new();
+    words.retain(|word| seen_words.insert(word.clone()));
+    previous_len - words.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, parse_word_file};
+
+    #[test]
+    fn empty_files_hold_no_words() {
+        assert_eq!(parse_word_file("", 5), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn too_short_words_are_reported() {
+        assert_eq!(
+            parse_word_file("crane\ncran\nslate\n", 5),
+            Err(ParseError {
+                bad_lines: vec![(2, "cran".to_string())],
+                expected_length: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn trailing_blank_lines_are_skipped() {
+        assert_eq!(
+            parse_word_file("crane\nslate\n\n\n", 5),
+            Ok(vec!["crane".to_string(), "slate".to_string()])
+        );
+    }
+}
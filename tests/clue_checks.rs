@@ -0,0 +1,34 @@
+use wordle_finder::{Constraints, matches};
+
+fn failed_checks(constraints: &Constraints, word: &str) -> Vec<&'static str> {
+    constraints
+        .clue_checks(word)
+        .into_iter()
+        .filter(|(_, is_satisfied)| !is_satisfied)
+        .map(|(label, _)| label)
+        .collect()
+}
+
+#[test]
+fn failed_checks_name_the_unmet_clues() {
+    let constraints = Constraints::new(vec![(0, 'c')], vec![(1, 'a')], "", "t");
+
+    assert!(failed_checks(&constraints, "clear").is_empty());
+    assert_eq!(
+        failed_checks(&constraints, "caste"),
+        vec!["Yellow letters", "Excluded letters"]
+    );
+}
+
+#[test]
+fn words_match_when_every_check_passes() {
+    let constraints = Constraints::new(vec![(4, 'e')], vec![(0, 's')], "L", "RT");
+
+    for word in ["slate", "lapse", "crane", "false", "elope", "pulse"] {
+        assert_eq!(
+            failed_checks(&constraints, word).is_empty(),
+            matches(word, &constraints),
+            "{word}"
+        );
+    }
+}
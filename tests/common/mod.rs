@@ -0,0 +1,4 @@
+/// Owned copies of `words`, like the lists loaded from a file
+pub fn word_list(words: &[&str]) -> Vec<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
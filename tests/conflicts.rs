@@ -0,0 +1,41 @@
+use wordle_finder::{Constraints, matches};
+
+#[test]
+fn gray_green_letter_is_reported_as_required_and_excluded() {
+    let constraints = Constraints::new(vec![(0, 'a')], Vec::new(), "", "a");
+
+    assert_eq!(
+        constraints.conflict(5).as_deref(),
+        Some("Conflict: A is both required and excluded")
+    );
+}
+
+#[test]
+fn yellow_letter_excluded_from_every_position_is_reported() {
+    let yellows = (0..5).map(|index| (index, 'e')).collect();
+    let constraints = Constraints::new(Vec::new(), yellows, "", "");
+
+    assert_eq!(
+        constraints.conflict(5).as_deref(),
+        Some("Conflict: E can't be at any position")
+    );
+}
+
+#[test]
+fn yellow_letter_whose_only_free_position_is_green_is_reported() {
+    let yellows = (0..4).map(|index| (index, 'e')).collect();
+    let constraints = Constraints::new(vec![(4, 's')], yellows, "", "");
+
+    assert_eq!(
+        constraints.conflict(5).as_deref(),
+        Some("Conflict: E can't be at any position")
+    );
+}
+
+#[test]
+fn consistent_clues_have_no_conflict() {
+    let constraints = Constraints::new(vec![(4, 'e')], vec![(0, 's')], "L", "RT");
+
+    assert_eq!(constraints.conflict(5), None);
+    assert!(matches("lapse", &constraints));
+}
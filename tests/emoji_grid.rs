@@ -0,0 +1,19 @@
+use wordle_finder::solver::{emoji_grid, format_guess_line, parse_guess_line};
+
+#[test]
+fn guesses_render_as_one_emoji_row_each() {
+    let guesses = [
+        parse_guess_line("CRANE:BYGBG").unwrap(),
+        parse_guess_line("GRACE:GGGGG").unwrap(),
+    ];
+
+    assert_eq!(emoji_grid(&guesses), "⬛🟨🟩⬛🟩\n🟩🟩🟩🟩🟩");
+}
+
+#[test]
+fn formatted_guess_lines_parse_back() {
+    let guess = parse_guess_line("crane:bygbg").unwrap();
+
+    assert_eq!(format_guess_line(&guess), "CRANE:BYGBG");
+    assert_eq!(parse_guess_line(&format_guess_line(&guess)), Ok(guess));
+}
@@ -0,0 +1,43 @@
+use wordle_finder::Constraints;
+
+fn greens(pattern: &str) -> Vec<(usize, char)> {
+    pattern
+        .chars()
+        .enumerate()
+        .filter(|(_, character)| character.is_alphabetic())
+        .collect()
+}
+
+#[test]
+fn excluded_letters_are_impossible() {
+    let constraints = Constraints::new(Vec::new(), Vec::new(), "", "XZ");
+
+    assert_eq!(constraints.impossible_letters(5), vec!['x', 'z']);
+}
+
+#[test]
+fn letters_capped_at_their_green_count_are_impossible() {
+    let constraints = Constraints::new(greens("e...."), Vec::new(), "", "E");
+
+    assert_eq!(constraints.impossible_letters(5), vec!['e']);
+}
+
+#[test]
+fn required_letters_crowd_out_the_others() {
+    let constraints = Constraints::new(greens("cra.."), Vec::new(), "NE", "");
+
+    let expected: Vec<char> = ('a'..='z')
+        .filter(|character| !['n', 'e'].contains(character))
+        .collect();
+    assert_eq!(constraints.impossible_letters(5), expected);
+}
+
+#[test]
+fn letters_excluded_from_every_unknown_position_are_impossible() {
+    let constraints = Constraints {
+        excluded_positions: vec![(4, 'y')],
+        ..Constraints::new(greens("cran."), Vec::new(), "", "")
+    };
+
+    assert_eq!(constraints.impossible_letters(5), vec!['y']);
+}
@@ -0,0 +1,106 @@
+use wordle_finder::{Constraints, constraints::IncludeMode, matches};
+
+fn including(letters: &str) -> Constraints {
+    Constraints::new(Vec::new(), Vec::new(), letters, "")
+}
+
+#[test]
+fn repeated_letter_requires_as_many_occurrences() {
+    let constraints = including("LL");
+
+    assert!(matches("loyal", &constraints));
+    assert!(matches("hello", &constraints));
+    assert!(!matches("lemon", &constraints));
+    assert!(!matches("crane", &constraints));
+}
+
+#[test]
+fn double_e_requires_two_es() {
+    let constraints = including("EE");
+
+    assert!(matches("geese", &constraints));
+    assert!(matches("sheep", &constraints));
+    assert!(!matches("crane", &constraints));
+}
+
+#[test]
+fn include_counts_ignore_case() {
+    assert_eq!(including("Ll"), including("LL"));
+}
+
+#[test]
+fn green_letter_counts_toward_include() {
+    let constraints = Constraints::new(vec![(4, 'E')], Vec::new(), "E", "");
+
+    assert!(matches("crane", &constraints));
+    assert!(matches("geese", &constraints));
+    assert!(!matches("crank", &constraints));
+}
+
+#[test]
+fn green_and_included_letter_is_required_once() {
+    let constraints = Constraints::new(vec![(1, 'E')], Vec::new(), "E", "");
+
+    assert_eq!(constraints.include_counts.get(&'e'), Some(&1));
+    assert!(matches("berth", &constraints));
+    assert!(matches("geese", &constraints));
+    assert!(!matches("crane", &constraints));
+}
+
+#[test]
+fn exclamation_mark_requires_exactly_as_many_occurrences() {
+    let at_least = including("EE");
+    let exactly = including("EE!");
+
+    assert!(matches("geese", &at_least));
+    assert!(!matches("geese", &exactly));
+    assert!(matches("sheep", &at_least));
+    assert!(matches("sheep", &exactly));
+    assert!(!matches("crane", &exactly));
+}
+
+#[test]
+fn exact_counts_only_apply_to_the_marked_letter() {
+    let constraints = including("E!LL");
+
+    assert!(matches("hello", &constraints));
+    assert!(!matches("eelly", &constraints));
+    assert_eq!(constraints.including, "ell");
+}
+
+fn including_with(letters: &str, include_mode: IncludeMode) -> Constraints {
+    Constraints::with_include_mode(Vec::new(), Vec::new(), letters, include_mode, "")
+}
+
+#[test]
+fn wildcards_stand_for_any_letter_in_a_substring() {
+    let constraints = including_with("R.N", IncludeMode::Substring);
+
+    assert!(matches("brine", &constraints));
+    assert!(matches("crane", &constraints));
+    assert!(!matches("snare", &constraints));
+    assert!(!matches("rainy", &constraints));
+    assert_eq!(constraints, including_with("R?N", IncludeMode::Substring));
+}
+
+#[test]
+fn wildcards_require_no_letter() {
+    let constraints = including("R.N");
+
+    assert_eq!(constraints.include_counts.len(), 2);
+    assert!(matches("rainy", &constraints));
+    assert!(matches("crane", &including_with("..", IncludeMode::AnyOf)));
+}
+
+#[test]
+fn wildcards_take_a_leftover_letter_in_an_anagram() {
+    let constraints = including_with("ERAC.", IncludeMode::Anagram);
+
+    assert!(matches("crane", &constraints));
+    assert!(matches("trace", &constraints));
+    assert!(!matches(
+        "react",
+        &including_with("ERAC", IncludeMode::Anagram)
+    ));
+    assert!(!matches("crepe", &constraints));
+}
@@ -0,0 +1,25 @@
+use wordle_finder::constraints::{normalize_excluding, normalize_letter_set, parse_excluding};
+
+#[test]
+fn pasted_letters_are_not_duplicated_by_typing() {
+    // Paste "rst" into the box, then type "R"
+    let letters = normalize_letter_set("rst");
+    let letters = normalize_letter_set(&format!("{letters}R"));
+
+    assert_eq!(letters, "RST");
+    assert_eq!(letters.matches('R').count(), 1);
+}
+
+#[test]
+fn other_characters_are_dropped() {
+    assert_eq!(normalize_letter_set("r, s & t!"), "RST");
+}
+
+#[test]
+fn positional_tokens_exclude_letters_from_a_position() {
+    let (letters, positions) = parse_excluding("st 2e 4A r");
+
+    assert_eq!(letters, "STR");
+    assert_eq!(positions, vec![(1, 'E'), (3, 'A')]);
+    assert_eq!(normalize_excluding("st 2e 4A r"), "STR 2E 4A");
+}
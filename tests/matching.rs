@@ -0,0 +1,17 @@
+use wordle_finder::{Constraints, matches};
+
+#[test]
+fn green_letters_must_be_at_their_position() {
+    let constraints = Constraints::new(vec![(0, 'C'), (4, 'E')], Vec::new(), "", "");
+
+    assert!(matches("crane", &constraints));
+    assert!(!matches("slate", &constraints));
+}
+
+#[test]
+fn words_too_short_for_a_green_letter_do_not_match() {
+    let constraints = Constraints::new(vec![(4, 'E')], Vec::new(), "", "");
+
+    assert!(!matches("abc", &constraints));
+    assert!(!matches("", &constraints));
+}
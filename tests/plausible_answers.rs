@@ -0,0 +1,33 @@
+mod common;
+
+use common::word_list;
+use wordle_finder::solver::{LetterFrequencies, is_plausible_answer};
+
+#[test]
+fn triple_letters_are_implausible() {
+    let candidates = word_list(&["eerie", "crane", "slate", "trace"]);
+    let stats = LetterFrequencies::new(&candidates);
+
+    assert!(!is_plausible_answer("eerie", &stats));
+    assert!(is_plausible_answer("crane", &stats));
+}
+
+#[test]
+fn words_of_rare_letters_only_are_implausible() {
+    let mut candidates = word_list(&["fuzzy"]);
+    candidates.extend((0..30).map(|_| "crane".to_string()));
+    let stats = LetterFrequencies::new(&candidates);
+
+    assert!(!is_plausible_answer("fuzzy", &stats));
+    assert!(is_plausible_answer("crane", &stats));
+}
+
+#[test]
+fn a_common_letter_makes_a_word_plausible() {
+    let mut candidates = word_list(&["fuzzy", "jazzy"]);
+    candidates.extend((0..30).map(|_| "crane".to_string()));
+    let stats = LetterFrequencies::new(&candidates);
+
+    // A is in most candidates
+    assert!(is_plausible_answer("jazzy", &stats));
+}
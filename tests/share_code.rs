@@ -0,0 +1,49 @@
+mod common;
+
+use common::word_list;
+use wordle_finder::constraints::IncludeMode;
+use wordle_finder::share::ShareCode;
+
+#[test]
+fn share_codes_round_trip() {
+    let share_code = ShareCode {
+        positions: word_list(&["A", "", "ST", "", "E"]),
+        position_excludes: word_list(&["", "R", "", "", "L"]),
+        including: "REE!".to_string(),
+        excluding: "NT 2O 4I".to_string(),
+        only_show_common: true,
+        include_mode: IncludeMode::Anagram,
+    };
+
+    let code = share_code.to_string();
+
+    assert_eq!(code, "A..ST..E/.R...L/REE!/NT_2O_4I/1/1");
+    assert_eq!(ShareCode::parse(&code), Ok(share_code));
+}
+
+#[test]
+fn empty_editors_round_trip() {
+    let share_code = ShareCode {
+        positions: word_list(&["", "", "", "", ""]),
+        position_excludes: word_list(&["", "", "", "", ""]),
+        ..ShareCode::default()
+    };
+
+    assert_eq!(share_code.to_string(), "..../....///0/0");
+    assert_eq!(ShareCode::parse("..../....///0/0"), Ok(share_code));
+}
+
+#[test]
+fn malformed_share_codes_are_rejected() {
+    assert!(ShareCode::parse("A...E/.R.../L/RST").is_err());
+    assert!(ShareCode::parse("A...E/.R.../L/RST/2").is_err());
+    assert!(ShareCode::parse("A.1..E/.R.../L/RST/0").is_err());
+    assert!(ShareCode::parse("A...E/.R.../L/RST/0/9").is_err());
+}
+
+#[test]
+fn codes_without_the_include_mode_match_at_least_the_letters() {
+    let share_code = ShareCode::parse("A...E/.R.../L/RST_2E/1").unwrap();
+
+    assert_eq!(share_code.include_mode, IncludeMode::AtLeast);
+}
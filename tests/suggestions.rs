@@ -0,0 +1,50 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::word_list;
+use wordle_finder::solver::{best_entropy_guess, distinct_vowel_count, suggest_guess};
+
+#[test]
+fn equally_scored_guesses_resolve_to_the_first_alphabetically() {
+    // Both guesses share one letter position with each candidate
+    let candidates = word_list(&["bat", "cot"]);
+
+    for guesses in [word_list(&["zzt", "aat"]), word_list(&["aat", "zzt"])] {
+        assert_eq!(
+            suggest_guess(&guesses, &candidates, &HashMap::new()).map(String::as_str),
+            Some("aat")
+        );
+    }
+}
+
+#[test]
+fn equally_informative_guesses_resolve_to_the_first_alphabetically() {
+    let candidates = word_list(&["bat", "cat"]);
+
+    for guesses in [word_list(&["cxx", "bxx"]), word_list(&["bxx", "cxx"])] {
+        assert_eq!(
+            best_entropy_guess(&guesses, &candidates, &HashMap::new()).as_deref(),
+            Some("bxx")
+        );
+    }
+}
+
+#[test]
+fn entropy_of_long_words_only_counts_the_patterns_that_occur() {
+    // 3^45 patterns are more than a usize can count
+    let candidates = word_list(&[&"a".repeat(45), &"b".repeat(45)]);
+
+    assert_eq!(
+        best_entropy_guess(&candidates, &candidates, &HashMap::new()),
+        Some("a".repeat(45))
+    );
+}
+
+#[test]
+fn repeated_vowels_count_once() {
+    assert_eq!(distinct_vowel_count("adieu"), 4);
+    assert_eq!(distinct_vowel_count("geese"), 1);
+    assert_eq!(distinct_vowel_count("LYMPH"), 0);
+    assert_eq!(distinct_vowel_count("Audio"), 4);
+}
@@ -0,0 +1,233 @@
+mod common;
+
+use common::word_list;
+use wordle_finder::words::{
+    DEFAULT_ALL_WORDS_FILE_PATH, NAMED_WORD_LISTS, dedupe_words, detect_word_length,
+    merge_missing_words, named_word_list, parse_ranked_word_file, parse_word_file,
+    word_list_location, words_up_to_rank,
+};
+use wordle_finder::{Constraints, matches};
+
+#[test]
+fn missing_common_words_are_appended() {
+    let mut words = word_list(&["crane", "slate"]);
+    let common_words = word_list(&["slate", "about", "crane", "zesty"]);
+
+    let missing_words = merge_missing_words(&mut words, &common_words);
+
+    assert_eq!(missing_words, word_list(&["about", "zesty"]));
+    assert_eq!(words, word_list(&["crane", "slate", "about", "zesty"]));
+}
+
+#[test]
+fn subset_common_words_leave_the_list_unchanged() {
+    let mut words = word_list(&["crane", "slate", "about"]);
+    let common_words = word_list(&["about", "crane"]);
+
+    assert!(merge_missing_words(&mut words, &common_words).is_empty());
+    assert_eq!(words, word_list(&["crane", "slate", "about"]));
+}
+
+const COMMENTED_LIST: &str = "\
+# Curated five letter words
+word,rank
+about,1
+# Added after the first draft
+crane,2
+
+slate
+";
+
+#[test]
+fn comments_and_header_are_skipped() {
+    let (words, ranks) = parse_ranked_word_file(COMMENTED_LIST, 5).unwrap();
+
+    assert_eq!(words, word_list(&["about", "crane", "slate"]));
+    assert_eq!(ranks.get("crane"), Some(&2));
+    assert!(!ranks.contains_key("word"));
+}
+
+#[test]
+fn words_past_the_rank_threshold_are_dropped() {
+    let (words, ranks) = parse_ranked_word_file(COMMENTED_LIST, 5).unwrap();
+
+    // The unranked slate stays
+    assert_eq!(
+        words_up_to_rank(&words, &ranks, 1),
+        word_list(&["about", "slate"])
+    );
+    assert_eq!(words_up_to_rank(&words, &ranks, 2), words);
+}
+
+#[test]
+fn word_length_is_detected_past_comments_and_header() {
+    assert_eq!(detect_word_length(COMMENTED_LIST), Some(5));
+}
+
+#[test]
+fn invalid_words_after_the_header_are_reported_at_their_line() {
+    let error = parse_word_file("word\nabout\n# comment\nbad\n", 5).unwrap_err();
+
+    assert_eq!(error.bad_lines, vec![(4, "bad".to_string())]);
+}
+
+#[test]
+fn every_invalid_word_is_reported() {
+    let error = parse_word_file("about\nfour\ncrane\nsixsix\n", 5).unwrap_err();
+
+    assert_eq!(
+        error.bad_lines,
+        vec![(2, "four".to_string()), (4, "sixsix".to_string())]
+    );
+}
+
+#[test]
+fn final_newline_is_ignored() {
+    assert_eq!(
+        parse_word_file("crane\nslate\n", 5).unwrap(),
+        word_list(&["crane", "slate"])
+    );
+}
+
+#[test]
+fn whitespace_only_lines_are_skipped() {
+    assert_eq!(
+        parse_word_file("crane\n     \n\t\nslate", 5).unwrap(),
+        word_list(&["crane", "slate"])
+    );
+}
+
+#[test]
+fn windows_line_endings_are_trimmed() {
+    assert_eq!(
+        parse_word_file("crane\r\nslate\r\n", 5).unwrap(),
+        word_list(&["crane", "slate"])
+    );
+    assert_eq!(
+        parse_word_file(" crane \r\r\n\tslate\r\n", 5).unwrap(),
+        word_list(&["crane", "slate"])
+    );
+}
+
+#[test]
+fn accented_words_are_counted_in_characters() {
+    // Six bytes, but five letters like the other words
+    assert_eq!("éclat".len(), 6);
+    assert_eq!(
+        parse_word_file("crane\néclat\n", 5).unwrap(),
+        word_list(&["crane", "éclat"])
+    );
+    assert!(parse_word_file("crane\néclats\n", 5).is_err());
+
+    // The position filter counts the same characters as the validator
+    let constraints = Constraints::new(vec![(0, 'é'), (4, 'T')], Vec::new(), "", "");
+    assert!(matches("éclat", &constraints));
+    assert!(!matches("crane", &constraints));
+}
+
+#[test]
+fn repeated_words_are_removed_keeping_the_first_ones() {
+    let mut words = word_list(&["crane", "slate", "crane", "about", "slate"]);
+
+    assert_eq!(dedupe_words(&mut words), 2);
+    assert_eq!(words, word_list(&["crane", "slate", "about"]));
+}
+
+#[test]
+fn word_lists_are_found_by_name_ignoring_case() {
+    assert_eq!(named_word_list("NYT"), Ok(&NAMED_WORD_LISTS[0]));
+    assert_eq!(
+        named_word_list("sgb").map(|word_list| word_list.name),
+        Ok("sgb")
+    );
+}
+
+#[test]
+fn unknown_word_list_names_list_the_available_ones() {
+    assert_eq!(
+        named_word_list("scrabble"),
+        Err("Unknown word list [scrabble], the available ones are nyt, sgb".to_string())
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_word_lists_carry_both_tiers() {
+    let contents = r#"{"all": ["crane", "about"], "common": ["about"], "language": "en"}"#;
+
+    let word_lists = wordle_finder::words::parse_json_word_lists(contents, None).unwrap();
+
+    assert_eq!(word_lists.all, word_list(&["crane", "about"]));
+    assert_eq!(word_lists.common, word_list(&["about"]));
+    assert_eq!(word_lists.length, Some(5));
+    assert_eq!(word_lists.language.as_deref(), Some("en"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_word_lists_reject_words_of_another_length() {
+    let contents = r#"{"all": ["crane"], "common": ["abouts"], "length": 5}"#;
+
+    assert!(wordle_finder::words::parse_json_word_lists(contents, None).is_err());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn every_json_word_of_another_length_is_reported() {
+    let contents = r#"{"all": ["four", "crane", "sixsix"], "common": ["abouts"], "length": 5}"#;
+
+    let error = wordle_finder::words::parse_json_word_lists(contents, None)
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("3 words weren't exactly 5 characters"),
+        "{error}"
+    );
+    for line in [
+        "The word [four] of the [all] list",
+        "The word [sixsix] of the [all] list",
+        "The word [abouts] of the [common] list",
+    ] {
+        assert!(error.contains(line), "{error}");
+    }
+}
+
+#[cfg(feature = "gui")]
+#[test]
+fn unreachable_urls_are_reported_as_download_errors() {
+    use wordle_finder::words::LoadError;
+
+    let error = wordle_finder::words::fetch_word_file("http://127.0.0.1:9/words.txt").unwrap_err();
+
+    assert!(
+        matches!(&error, LoadError::Fetch { url, .. } if url == "http://127.0.0.1:9/words.txt"),
+        "{error}"
+    );
+}
+
+#[test]
+fn the_location_of_a_given_word_list_is_its_path() {
+    assert_eq!(
+        word_list_location(
+            Some("lists/mine.txt"),
+            DEFAULT_ALL_WORDS_FILE_PATH,
+            "the built-in list",
+        ),
+        "lists/mine.txt"
+    );
+}
+
+#[test]
+fn a_first_word_of_another_length_is_reported_rather_than_skipped() {
+    let contents = "four\ncrane\nslate";
+
+    assert_eq!(detect_word_length(contents), Some(5));
+    let error = parse_word_file(contents, 5).unwrap_err();
+    assert_eq!(error.bad_lines, vec![(1, "four".to_string())]);
+}
+
+#[test]
+fn word_length_is_the_most_common_one() {
+    assert_eq!(detect_word_length("crane\nfour\nslate\nabout"), Some(5));
+}